@@ -0,0 +1,4 @@
+.POSIX:
+all:: build
+	echo done
+build:;
@@ -0,0 +1,5 @@
+.POSIX:
+.PHONY: all
+
+all:
+	$(MAKE) -C foo install
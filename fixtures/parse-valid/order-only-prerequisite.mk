@@ -0,0 +1,5 @@
+.POSIX:
+all: build | deps
+	echo done
+build:;
+deps:;
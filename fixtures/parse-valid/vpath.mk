@@ -0,0 +1,4 @@
+vpath %.c src
+
+foo: foo.c
+	gcc -o foo foo.c
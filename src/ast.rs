@@ -14,6 +14,9 @@ pub static UPPERCASE_ALPHABETIC: RangeInclusive<char> = 'A'..='Z';
 
 lazy_static::lazy_static! {
     /// SPECIAL_TARGETS collects POSIX special target names.
+    ///
+    /// Stable API: downstream integrations may query this set
+    /// rather than hardcode the POSIX special target list.
     pub static ref SPECIAL_TARGETS: HashSet<String> = vec![
             ".POSIX",
             ".DEFAULT",
@@ -29,6 +32,39 @@ lazy_static::lazy_static! {
         .into_iter()
         .map(|e| e.to_string())
         .collect::<HashSet<String>>();
+
+    /// EXPORT_SPECIAL_TARGETS collects implementation-specific special target names
+    /// that leak make macros into the command environment.
+    ///
+    /// Stable API: downstream integrations may query this set.
+    pub static ref EXPORT_SPECIAL_TARGETS: HashSet<String> = vec![
+            ".EXPORT",
+            ".EXPORT-ENV",
+            ".EXPORT_ALL_VARIABLES",
+            ".NOEXPORT",
+        ]
+        .into_iter()
+        .map(|e| e.to_string())
+        .collect::<HashSet<String>>();
+}
+
+/// is_special_target reports whether a target name denotes
+/// a special target, either a POSIX special target
+/// or an implementation-specific export special target.
+///
+/// Stable API: downstream integrations may query this predicate.
+/// The backing sets are [SPECIAL_TARGETS] and [EXPORT_SPECIAL_TARGETS].
+pub fn is_special_target(name: &str) -> bool {
+    SPECIAL_TARGETS.contains(name) || EXPORT_SPECIAL_TARGETS.contains(name)
+}
+
+#[test]
+fn test_is_special_target() {
+    assert!(is_special_target(".POSIX"));
+    assert!(is_special_target(".PHONY"));
+    assert!(is_special_target(".EXPORT_ALL_VARIABLES"));
+    assert!(!is_special_target("all"));
+    assert!(!is_special_target(".posix"));
 }
 
 /// Traceable prepares an AST entry to receive updates
@@ -77,6 +113,14 @@ pub enum Ore {
         /// ps denotes any prerequisite(s) depended on by this rule.
         ps: Vec<String>,
 
+        /// os denotes any order-only prerequisite(s) depended on by this rule,
+        /// following a "|" separator (GNU extension).
+        os: Vec<String>,
+
+        /// dc denotes whether this rule separates targets from prerequisites
+        /// with a double-colon "::" (GNU/BSD extension).
+        dc: bool,
+
         /// cs denotes any shell command(s) executed by this rule.
         cs: Vec<String>,
     },
@@ -88,6 +132,10 @@ pub enum Ore {
         /// n denotes a name for this macro.
         n: String,
 
+        /// op denotes the assignment operator for this macro,
+        /// e.g. "=", "?=", "+=", "!=", "::=", ":::=".
+        op: String,
+
         /// v denotes an unexpanded value for this macro.
         v: String,
     },
@@ -98,11 +146,26 @@ pub enum Ore {
         ps: Vec<String>,
     },
 
+    /// Vp models a vpath directive line (GNU extension).
+    Vp {
+        /// ps collects the pattern and search directory tokens.
+        ps: Vec<String>,
+    },
+
     /// Ex models a general macro expression.
     Ex {
         /// e denotes an unexpanded macro expression.
         e: String,
     },
+
+    /// Cm models a full line comment.
+    ///
+    /// Cm nodes are only produced by [parse_posix_with_comments].
+    Cm {
+        /// c denotes the comment text,
+        /// sans the leading sharp sign and surrounding whitespace.
+        c: String,
+    },
 }
 
 /// Gem provides tokens enriched
@@ -245,7 +308,7 @@ parser! {
 
         rule non_special_target_literal() -> &'input str =
             quiet!{
-                s:$([^ (' ' | '\t' | ':' | ';' | '=' | '#' | '\r' | '\n' | '\\')]+) {?
+                s:$([^ (' ' | '\t' | ':' | ';' | '=' | '#' | '|' | '\r' | '\n' | '\\')]+) {?
                     if SPECIAL_TARGETS.contains(s) {
                         Err("special target")
                     } else {
@@ -269,6 +332,11 @@ parser! {
         rule prerequisite() -> String =
             (wait_prerequisite() / target())
 
+        rule order_only_prerequisites() -> Vec<String> =
+            "|" _ os:(prerequisite() ++ _) {
+                os
+            }
+
         rule simple_command() -> String =
             quiet!{
                 s:$((("\\" (! line_ending())) / [^ ('\\' | '\n' | '\r')])+) {
@@ -300,14 +368,14 @@ parser! {
                 s
             }
 
-        rule with_prerequisites() -> (Vec<String>, Vec<String>) =
-            ps:(prerequisite() ++ _) _ inline_commands:(inline_command()*<0, 1>) ((comment() / line_ending())+ / eof()) indented_commands:(indented_command()*) {
-                (ps, [inline_commands, indented_commands].concat())
+        rule with_prerequisites() -> (Vec<String>, Vec<String>, Vec<String>) =
+            ps:(prerequisite() ++ _) _ os:(order_only_prerequisites()*<0, 1>) _ inline_commands:(inline_command()*<0, 1>) ((comment() / line_ending())+ / eof()) indented_commands:(indented_command()*) {
+                (ps, os.concat(), [inline_commands, indented_commands].concat())
             }
 
-        rule with_prerequisites_without_commands() -> (Vec<String>, Vec<String>) =
-            ps:(prerequisite() ++ _) _ ((comment() / line_ending())+ / eof()) {
-                (ps, Vec::new())
+        rule with_prerequisites_without_commands() -> (Vec<String>, Vec<String>, Vec<String>) =
+            ps:(prerequisite() ++ _) _ os:(order_only_prerequisites()*<0, 1>) _ ((comment() / line_ending())+ / eof()) {
+                (ps, os.concat(), Vec::new())
             }
 
         rule commands_with_inline() -> Vec<String> =
@@ -320,14 +388,14 @@ parser! {
                 indented_commands
             }
 
-        rule without_prerequisites() -> (Vec<String>, Vec<String>) =
+        rule without_prerequisites() -> (Vec<String>, Vec<String>, Vec<String>) =
             cs:(commands_with_inline() / commands_without_inline()) {
-                (Vec::new(), cs)
+                (Vec::new(), Vec::new(), cs)
             }
 
-        rule without_prerequisites_without_commands() -> (Vec<String>, Vec<String>) =
+        rule without_prerequisites_without_commands() -> (Vec<String>, Vec<String>, Vec<String>) =
             ((comment() / line_ending())+ / eof()) {
-                (Vec::new(), Vec::new())
+                (Vec::new(), Vec::new(), Vec::new())
             }
 
         rule special_unit_target() -> String =
@@ -337,7 +405,7 @@ parser! {
                 }
             } / expected!("target")
 
-        rule special_unit_rule() -> (Vec<String>, (Vec<String>, Vec<String>)) =
+        rule special_unit_rule() -> (Vec<String>, (Vec<String>, Vec<String>, Vec<String>)) =
             t:special_unit_target() _ ":" _ pcs:without_prerequisites_without_commands() {
                 (vec![t.to_string()], pcs)
             }
@@ -349,7 +417,7 @@ parser! {
                 }
             } / expected!("target")
 
-        rule special_commands_rule() -> (Vec<String>, (Vec<String>, Vec<String>)) =
+        rule special_commands_rule() -> (Vec<String>, (Vec<String>, Vec<String>, Vec<String>)) =
             t:special_commands_target() _ ":" _ pcs:without_prerequisites() {
                 (vec![t], pcs)
             }
@@ -361,14 +429,14 @@ parser! {
                 }
             } / expected!("target")
 
-        rule special_target_config_rule() -> (Vec<String>, (Vec<String>, Vec<String>)) =
+        rule special_target_config_rule() -> (Vec<String>, (Vec<String>, Vec<String>, Vec<String>)) =
             t:special_config_target() _ ":" _ pcs:(with_prerequisites_without_commands() / without_prerequisites_without_commands()) {
                 (vec![t], pcs)
             }
 
         rule special_target_rule() -> Gem =
             (comment() / line_ending())* p:position!() tpcs:(special_unit_rule() / special_commands_rule() / special_target_config_rule()) {
-                let (ts, (ps, cs)) = tpcs;
+                let (ts, (ps, os, cs)) = tpcs;
 
                 Gem {
                     o: p,
@@ -376,14 +444,16 @@ parser! {
                     n: Ore::Ru {
                         ts,
                         ps,
+                        os,
+                        dc: false,
                         cs: cs.into_iter().filter(|e| !e.is_empty()).collect(),
                     }
                 }
             }
 
         rule make_rule() -> Gem =
-            (comment() / line_ending())* p:position!() ts:(target() ++ _) _ ":" _ pcs:(with_prerequisites() / without_prerequisites()) {
-                let (ps, cs) = pcs;
+            (comment() / line_ending())* p:position!() ts:(target() ++ _) _ dc:$("::" / ":") _ pcs:(with_prerequisites() / without_prerequisites()) {
+                let (ps, os, cs) = pcs;
 
                 Gem {
                     o: p,
@@ -391,6 +461,8 @@ parser! {
                     n: Ore::Ru {
                         ts,
                         ps,
+                        os,
+                        dc: dc == "::",
                         cs: cs.into_iter().filter(|e| !e.is_empty()).collect(),
                     },
                 }
@@ -430,12 +502,13 @@ parser! {
             } / expected!("assignment operator")
 
         rule macro_definition() -> Gem =
-            (comment() / line_ending())* p:position!() n:macro_name() _ assignment_operator() _ v:macro_value() {
+            (comment() / line_ending())* p:position!() n:macro_name() _ op:assignment_operator() _ v:macro_value() {
                 Gem {
                     o: p,
                     l: 0,
                     n: Ore::Mc {
                         n,
+                        op: op.to_string(),
                         v,
                     },
                 }
@@ -467,6 +540,27 @@ parser! {
                 }
             }
 
+        rule vpath_opening() =
+            quiet!{
+                "vpath"
+            } / expected!("vpath opening")
+
+        rule vpath_argument() -> String =
+            __ s:include_value() {
+                s
+            }
+
+        rule vpath() -> Gem =
+            (comment() / line_ending())* p:position!() vpath_opening() ps:(vpath_argument()*) _ ((comment() / line_ending())+ / eof()) {
+                Gem {
+                    o: p,
+                    l: 0,
+                    n: Ore::Vp {
+                        ps,
+                    },
+                }
+            }
+
         rule general_expression() -> Gem =
             (comment() / line_ending())* p:position!() expression:macro_expansion() remainder:(macro_value()?) {
                 Gem {
@@ -479,7 +573,7 @@ parser! {
             }
 
         rule node() -> Gem =
-            n:(special_target_rule() / make_rule() / include() / macro_definition() / general_expression()) {
+            n:(special_target_rule() / make_rule() / vpath() / include() / macro_definition() / general_expression()) {
                 n
             }
 
@@ -490,58 +584,341 @@ parser! {
     }
 }
 
+/// LineIndex maps byte offsets to line numbers,
+/// supporting offset to line and column conversion
+/// without repeated string scans.
+#[derive(Debug, PartialEq)]
+pub struct LineIndex {
+    /// ranges maps byte ranges to 1-indexed line numbers.
+    pub ranges: HashMap<Range<usize>, usize>,
+}
+
+impl LineIndex {
+    /// line_of queries the 1-indexed line number containing a byte offset.
+    ///
+    /// Out of range offsets report zero.
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.ranges
+            .iter()
+            .find(|(r, _)| r.contains(&offset))
+            .map(|(_, line)| *line)
+            .unwrap_or(0)
+    }
+
+    /// column_of queries the 1-indexed column number of a byte offset.
+    ///
+    /// Out of range offsets report zero.
+    pub fn column_of(&self, offset: usize) -> usize {
+        self.ranges
+            .iter()
+            .find(|(r, _)| r.contains(&offset))
+            .map(|(r, line)| match line {
+                1 => 1 + offset,
+                _ => offset - r.start,
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// line_index constructs a LineIndex for a string.
+pub fn line_index(s: &str) -> LineIndex {
+    LineIndex {
+        ranges: [
+            vec![0],
+            s.match_indices('\n').map(|(offset, _)| offset).collect(),
+            vec![s.len()],
+        ]
+        .concat()
+        .windows(2)
+        .enumerate()
+        .map(|(i, window)| {
+            (
+                Range {
+                    start: window[0],
+                    end: window[1],
+                },
+                1 + i,
+            )
+        })
+        .collect(),
+    }
+}
+
+#[test]
+fn test_line_index() {
+    let index: LineIndex = line_index("PKG = curl\nall:\n\techo \"Hello World!\"\n");
+
+    assert_eq!(index.line_of(0), 1);
+    assert_eq!(index.line_of(4), 1);
+    assert_eq!(index.line_of(11), 2);
+    assert_eq!(index.line_of(16), 3);
+
+    assert_eq!(index.column_of(0), 1);
+    assert_eq!(index.column_of(4), 5);
+    assert_eq!(index.column_of(11), 1);
+    assert_eq!(index.column_of(12), 2);
+}
+
+/// render_parse_error formats a low level parser error for console use.
+fn render_parse_error(pth: &str, s: &str, err: peg::error::ParseError<peg::str::LineCol>) -> String {
+    let loc: peg::str::LineCol = err.location;
+
+    let mut valid_tokens: Vec<&str> = err
+        .expected
+        .tokens()
+        .collect::<HashSet<&str>>()
+        .into_iter()
+        .collect();
+    valid_tokens.sort();
+
+    let bad_token: String = s
+        .chars()
+        .nth(loc.offset)
+        .map(|e| format!("\"{}\"", e.to_string().escape_debug()))
+        .unwrap_or("EOF".to_string());
+
+    let bad_line: &str = s.lines().nth(loc.line - 1).unwrap_or("").trim();
+
+    if let Some(rest) = bad_line.strip_prefix(".POSIX") {
+        let ps: &str = rest.trim_start().trim_start_matches(':').trim();
+
+        if !ps.is_empty() && !ps.starts_with('#') {
+            return format!(
+                "error: {}:{}:{} .POSIX accepts no prerequisites; declare a bare \".POSIX:\" marker",
+                pth, loc.line, loc.column
+            );
+        }
+    }
+
+    let trailing_backslashes: usize = s
+        .trim_end_matches(['\r', '\n'])
+        .chars()
+        .rev()
+        .take_while(|c| *c == '\\')
+        .count();
+
+    if bad_token == "EOF" && trailing_backslashes % 2 == 1 {
+        return format!(
+            "error: {}:{}:{} dangling line continuation backslash at end of file; remove the backslash or complete the continuation",
+            pth, loc.line, loc.column
+        );
+    }
+
+    format!(
+        "error: {}:{}:{} found {}, expected: {}",
+        pth,
+        loc.line,
+        loc.column,
+        bad_token,
+        valid_tokens.join(", ")
+    )
+}
+
 /// parse_posix generates a makefile AST from a string.
 pub fn parse_posix(pth: &str, s: &str) -> Result<Mk, String> {
-    let mut ast: Mk = parser::parse(s).map_err(|err| {
-        let loc: peg::str::LineCol = err.location;
+    let mut ast: Mk = parser::parse(s).map_err(|err| render_parse_error(pth, s, err))?;
+    ast.update(&line_index(s).ranges);
+    Ok(ast)
+}
 
-        let mut valid_tokens: Vec<&str> = err
-            .expected
-            .tokens()
-            .collect::<HashSet<&str>>()
-            .into_iter()
-            .collect();
-        valid_tokens.sort();
-
-        let bad_token: String = s
-            .chars()
-            .nth(loc.offset)
-            .map(|e| format!("\"{}\"", e.to_string().escape_debug()))
-            .unwrap_or("EOF".to_string());
-
-        format!(
-            "error: {}:{}:{} found {}, expected: {}",
-            pth,
-            loc.line,
-            loc.column,
-            bad_token,
-            valid_tokens.join(", ")
-        )
-    })?;
-
-    let index: HashMap<Range<usize>, usize> = [
-        vec![0],
-        s.match_indices('\n').map(|(offset, _)| offset).collect(),
-        vec![s.len()],
-    ]
-    .concat()
-    .windows(2)
-    .enumerate()
-    .map(|(i, window)| {
-        (
-            Range {
-                start: window[0],
-                end: window[1],
-            },
-            1 + i,
-        )
-    })
-    .collect();
+/// parse_posix_recover generates a makefile AST from a string,
+/// skipping past node level syntax errors.
+///
+/// On a parse failure, the offending line is blanked,
+/// preserving line numbering, and parsing retries.
+/// Returns the partial AST alongside any parse error messages.
+pub fn parse_posix_recover(pth: &str, s: &str) -> (Mk, Vec<String>) {
+    let mut working: String = s.to_string();
+    let mut errors: Vec<String> = Vec::new();
+
+    loop {
+        match parser::parse(&working) {
+            Ok(mut ast) => {
+                ast.update(&line_index(&working).ranges);
+                return (ast, errors);
+            }
+            Err(err) => {
+                let bad_line: usize = err.location.line;
+                errors.push(render_parse_error(pth, &working, err));
+
+                let blanked: String = working
+                    .split_inclusive('\n')
+                    .enumerate()
+                    .map(|(i, text)| match 1 + i {
+                        l if l == bad_line => match text {
+                            t if t.ends_with("\r\n") => "\r\n",
+                            t if t.ends_with('\n') => "\n",
+                            _ => "",
+                        },
+                        _ => text,
+                    })
+                    .collect::<String>();
+
+                if blanked == working {
+                    return (Mk::new(Vec::new()), errors);
+                }
+
+                working = blanked;
+            }
+        }
+    }
+}
+
+/// parse_posix_with_comments generates a makefile AST from a string,
+/// retaining full line comments as [Ore::Cm] nodes interleaved by offset.
+///
+/// Tab indented comments belong to rule commands,
+/// forwarding to the shell interpreter rather than make,
+/// and are not retained here.
+///
+/// [parse_posix] remains comment-free.
+pub fn parse_posix_with_comments(pth: &str, s: &str) -> Result<Mk, String> {
+    let mut ast: Mk = parse_posix(pth, s)?;
+    let index: LineIndex = line_index(s);
+    let mut offset: usize = 0;
+
+    for line in s.split_inclusive('\n') {
+        let trimmed: &str = line.trim_start_matches(' ');
+
+        if trimmed.starts_with('#') {
+            let o: usize = offset + line.len() - trimmed.len();
+
+            ast.ns.push(Gem {
+                o,
+                l: index.line_of(o),
+                n: Ore::Cm {
+                    c: trimmed
+                        .trim_start_matches('#')
+                        .trim_end_matches(['\r', '\n'])
+                        .trim()
+                        .to_string(),
+                },
+            });
+        }
+
+        offset += line.len();
+    }
 
-    ast.update(&index);
+    ast.ns.sort_by_key(|e| e.o);
     Ok(ast)
 }
 
+#[test]
+fn test_parse_posix_recover() {
+    let (ast, errors) = parse_posix_recover("-", "fo:::o\nPKG = curl\nba:::r\n");
+
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].contains("-:1:"));
+    assert!(errors[1].contains("-:3:"));
+
+    assert_eq!(
+        ast.ns,
+        vec![Gem {
+            o: 1,
+            l: 2,
+            n: Ore::Mc {
+                n: "PKG".to_string(),
+                op: "=".to_string(),
+                v: "curl".to_string(),
+            },
+        }]
+    );
+
+    let (clean_ast, clean_errors) = parse_posix_recover("-", "PKG = curl\n");
+    assert!(clean_errors.is_empty());
+    assert_eq!(clean_ast.ns.len(), 1);
+}
+
+#[test]
+fn test_dangling_continuation_diagnostic() {
+    assert!(parse_posix("-", "all:\n\techo foo \\\n")
+        .unwrap_err()
+        .contains("dangling line continuation"));
+
+    assert!(parse_posix("-", "PKG = curl \\\n")
+        .unwrap_err()
+        .contains("dangling line continuation"));
+
+    assert!(parse_posix("-", "all:\n\techo foo \\\n\techo bar\n").is_ok());
+
+    assert!(!parse_posix("-", "fo:::o\n")
+        .unwrap_err()
+        .contains("dangling line continuation"));
+}
+
+#[test]
+fn test_posix_marker_prerequisite_diagnostic() {
+    assert!(parse_posix("-", ".POSIX: foo\n")
+        .unwrap_err()
+        .contains(".POSIX accepts no prerequisites"));
+
+    assert!(parse_posix("-", ".POSIX:\nall:\n\techo \"Hello World!\"\n").is_ok());
+
+    assert!(!parse_posix("-", "fo:::o\n")
+        .unwrap_err()
+        .contains(".POSIX accepts no prerequisites"));
+}
+
+#[test]
+fn test_parse_posix_with_comments() {
+    let makefile_str: &str = "# alphabet\nA=apple\n";
+
+    assert_eq!(
+        parse_posix_with_comments("-", makefile_str).unwrap().ns,
+        vec![
+            Gem {
+                o: 0,
+                l: 1,
+                n: Ore::Cm {
+                    c: "alphabet".to_string(),
+                }
+            },
+            Gem {
+                o: 11,
+                l: 2,
+                n: Ore::Mc {
+                    n: "A".to_string(),
+                    op: "=".to_string(),
+                    v: "apple".to_string(),
+                }
+            },
+        ]
+    );
+
+    assert_eq!(
+        parse_posix("-", makefile_str)
+            .unwrap()
+            .ns
+            .into_iter()
+            .map(|e| e.n)
+            .collect::<Vec<Ore>>(),
+        vec![Ore::Mc {
+            n: "A".to_string(),
+            op: "=".to_string(),
+            v: "apple".to_string(),
+        }]
+    );
+
+    assert_eq!(
+        parse_posix_with_comments("-", "all:\n\t#shell comment\n\techo \"Hello World!\"\n")
+            .unwrap()
+            .ns
+            .into_iter()
+            .map(|e| e.n)
+            .collect::<Vec<Ore>>(),
+        vec![Ore::Ru {
+            ts: vec!["all".to_string()],
+            ps: Vec::new(),
+            os: Vec::new(),
+            dc: false,
+            cs: vec![
+                "#shell comment".to_string(),
+                "echo \"Hello World!\"".to_string(),
+            ],
+        }]
+    );
+}
+
 #[test]
 fn test_grammar() {
     use self::walkdir;
@@ -560,7 +937,7 @@ fn test_grammar() {
         }
 
         let pth_display: path::Display = pth.display();
-        let makefile_str: &str = &fs::read_to_string(&pth).unwrap();
+        let makefile_str: &str = &fs::read_to_string(pth).unwrap();
         assert!(parse_posix(&pth_display.to_string(), makefile_str)
             .map_err(|err| format!("unable to parse {}: {}", &pth_display, err))
             .is_ok());
@@ -580,7 +957,7 @@ fn test_grammar() {
         }
 
         let pth_string: String = pth.display().to_string();
-        let makefile_str: &str = &fs::read_to_string(&pth).unwrap();
+        let makefile_str: &str = &fs::read_to_string(pth).unwrap();
         assert!(
             parse_posix(&pth_string, makefile_str).is_err(),
             "failed to reject {}",
@@ -616,6 +993,7 @@ fn test_whitespace() {
             .collect::<Vec<Ore>>(),
         vec![Ore::Mc {
             n: "BLANK".to_string(),
+            op: "=".to_string(),
             v: String::new(),
         }]
     );
@@ -629,6 +1007,7 @@ fn test_whitespace() {
             .collect::<Vec<Ore>>(),
         vec![Ore::Mc {
             n: "C".to_string(),
+            op: "=".to_string(),
             v: "c ".to_string(),
         }]
     );
@@ -651,6 +1030,8 @@ fn test_whitespace() {
                 "b-1.txt".to_string(),
                 "c-1.txt".to_string(),
             ],
+            os: Vec::new(),
+            dc: false,
             cs: vec![
                 "cp a-1.txt a-2.txt".to_string(),
                 "cp b-1.txt b-2.txt".to_string(),
@@ -676,6 +1057,33 @@ fn test_whitespace() {
     assert!(parse_posix("-", "includeabc\n").is_err());
 }
 
+#[test]
+fn test_vpath() {
+    assert_eq!(
+        parse_posix("-", "vpath %.c src\n")
+            .unwrap()
+            .ns
+            .into_iter()
+            .map(|e| e.n)
+            .collect::<Vec<Ore>>(),
+        vec![Ore::Vp {
+            ps: vec!["%.c".to_string(), "src".to_string()]
+        }]
+    );
+
+    assert_eq!(
+        parse_posix("-", "vpath\n")
+            .unwrap()
+            .ns
+            .into_iter()
+            .map(|e| e.n)
+            .collect::<Vec<Ore>>(),
+        vec![Ore::Vp { ps: Vec::new() }]
+    );
+
+    assert!(parse_posix("-", "vpathabc\n").is_err());
+}
+
 #[test]
 fn test_comments() {
     assert_eq!(
@@ -702,6 +1110,7 @@ fn test_comments() {
             .collect::<Vec<Ore>>(),
         vec![Ore::Mc {
             n: "C".to_string(),
+            op: "=".to_string(),
             v: "c".to_string(),
         }]
     );
@@ -716,9 +1125,44 @@ fn test_comments() {
         vec![Ore::Ru {
             ts: vec!["foo".to_string()],
             ps: vec!["foo.c".to_string()],
+            os: Vec::new(),
+            dc: false,
+            cs: vec!["gcc -o foo foo.c".to_string()],
+        }]
+    );
+}
+
+#[test]
+fn test_double_colon_rules() {
+    assert_eq!(
+        parse_posix("-", "foo:: foo.c\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .ns
+            .into_iter()
+            .map(|e| e.n)
+            .collect::<Vec<Ore>>(),
+        vec![Ore::Ru {
+            ts: vec!["foo".to_string()],
+            ps: vec!["foo.c".to_string()],
+            os: Vec::new(),
+            dc: true,
             cs: vec!["gcc -o foo foo.c".to_string()],
         }]
     );
+
+    assert_eq!(
+        parse_posix("-", "PKG ::= curl\n")
+            .unwrap()
+            .ns
+            .into_iter()
+            .map(|e| e.n)
+            .collect::<Vec<Ore>>(),
+        vec![Ore::Mc {
+            n: "PKG".to_string(),
+            op: "::=".to_string(),
+            v: "curl".to_string(),
+        }]
+    );
 }
 
 #[test]
@@ -730,6 +1174,7 @@ fn test_offsets_and_line_numbers() {
             l: 2,
             n: Ore::Mc {
                 n: "A".to_string(),
+                op: "=".to_string(),
                 v: "apple".to_string(),
             }
         }]
@@ -748,6 +1193,8 @@ fn test_c_family_escape_preservation() {
         vec![Ore::Ru {
             ts: vec!["all".to_string()],
             ps: Vec::new(),
+            os: Vec::new(),
+            dc: false,
             cs: vec!["printf \"Hello World!\\\n\"".to_string()],
         }]
     );
@@ -761,6 +1208,7 @@ fn test_c_family_escape_preservation() {
             .collect::<Vec<Ore>>(),
         vec![Ore::Mc {
             n: "MSG".to_string(),
+            op: "=".to_string(),
             v: "\"Hello World!\\n\"".to_string(),
         }]
     );
@@ -777,6 +1225,7 @@ fn test_multiline_expressions() {
             .collect::<Vec<Ore>>(),
         vec![Ore::Mc {
             n: "FULL_NAME".to_string(),
+            op: "=".to_string(),
             v: "Alice Liddell".to_string(),
         }]
     );
@@ -791,6 +1240,8 @@ fn test_multiline_expressions() {
         vec![Ore::Ru {
             ts: vec!["foo".to_string()],
             ps: vec!["foo.c".to_string()],
+            os: Vec::new(),
+            dc: false,
             cs: vec!["gcc\\\n-o foo\\\nfoo.c".to_string()],
         }]
     );
@@ -816,6 +1267,8 @@ fn test_multiline_expressions() {
                 "test-2".to_string(),
                 "test-3".to_string(),
             ],
+            os: Vec::new(),
+            dc: false,
             cs: Vec::new(),
         }]
     );
@@ -833,6 +1286,8 @@ fn test_backslash_prefixed_values() {
         vec![Ore::Ru {
             ts: vec!["all".to_string()],
             ps: Vec::new(),
+            os: Vec::new(),
+            dc: false,
             cs: vec!["\\curl --version".to_string()]
         }]
     );
@@ -846,6 +1301,7 @@ fn test_backslash_prefixed_values() {
             .collect::<Vec<Ore>>(),
         vec![Ore::Mc {
             n: "CLIENT".to_string(),
+            op: "=".to_string(),
             v: "\\curl".to_string()
         }]
     );
@@ -2,16 +2,20 @@
 
 extern crate die;
 extern crate getopts;
+extern crate glob;
+extern crate serde_json;
 extern crate unmake;
 extern crate walkdir;
 
-use self::unmake::{inspect, warnings};
+use self::unmake::{config, inspect, warnings};
 use die::{die, Die};
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
 use std::io;
 use std::path;
 use std::process;
+use std::time;
 
 lazy_static::lazy_static! {
     /// DIRECTORY_EXCLUSIONS
@@ -22,6 +26,328 @@ lazy_static::lazy_static! {
     ];
 }
 
+/// IGNORE_FILENAME denotes the conventional name for unmake ignore files.
+pub static IGNORE_FILENAME: &str = ".unmakeignore";
+
+/// DEFAULT_MAX_INCLUDE_DEPTH bounds include chain traversal.
+pub static DEFAULT_MAX_INCLUDE_DEPTH: usize = 8;
+
+/// scan_include_chain walks makefile include chains,
+/// reporting cycles and chains nested deeper than max_depth.
+///
+/// Include paths referencing macro expansions are skipped,
+/// as unmake does not evaluate makefiles.
+fn scan_include_chain(
+    pth: &path::Path,
+    max_depth: usize,
+    stack: &mut Vec<path::PathBuf>,
+    reports: &mut Vec<String>,
+) {
+    let canonical: path::PathBuf = match pth.canonicalize() {
+        Err(_) => return,
+        Ok(p) => p,
+    };
+
+    if stack.contains(&canonical) {
+        reports.push(format!(
+            "error: {}: include cycle detected via {}",
+            stack[0].display(),
+            pth.display()
+        ));
+        return;
+    }
+
+    let makefile_str: String = match fs::read_to_string(&canonical) {
+        Err(_) => return,
+        Ok(s) => s,
+    };
+
+    let ast: unmake::ast::Mk =
+        match unmake::ast::parse_posix(&pth.display().to_string(), &makefile_str) {
+            Err(_) => return,
+            Ok(a) => a,
+        };
+
+    stack.push(canonical);
+
+    for gem in &ast.ns {
+        if let unmake::ast::Ore::In { ps } = &gem.n {
+            for include_pth_string in ps {
+                if include_pth_string.contains('$') {
+                    continue;
+                }
+
+                if stack.len() >= max_depth {
+                    reports.push(format!(
+                        "error: {}:{} include chain exceeds depth {}: {}",
+                        pth.display(),
+                        gem.l,
+                        max_depth,
+                        stack
+                            .iter()
+                            .map(|e| e.display().to_string())
+                            .collect::<Vec<String>>()
+                            .join(" -> ")
+                    ));
+                    continue;
+                }
+
+                let child_pth: path::PathBuf = pth
+                    .parent()
+                    .unwrap_or(path::Path::new("."))
+                    .join(include_pth_string);
+                scan_include_chain(&child_pth, max_depth, stack, reports);
+            }
+        }
+    }
+
+    stack.pop();
+}
+
+/// load_ignore_patterns reads any ignore file residing directly in the given directory.
+///
+/// Blank lines and comment lines beginning with "#" are skipped.
+/// Remaining lines parse as glob patterns,
+/// relative to the directory housing the ignore file.
+fn load_ignore_patterns(dir: &path::Path) -> Vec<glob::Pattern> {
+    let ignore_pth: path::PathBuf = dir.join(IGNORE_FILENAME);
+
+    let ignore_str: String = match fs::read_to_string(&ignore_pth) {
+        Err(_) => return Vec::new(),
+        Ok(s) => s,
+    };
+
+    let mut patterns: Vec<glob::Pattern> = Vec::new();
+
+    for line in ignore_str.lines() {
+        let trimmed: &str = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        match glob::Pattern::new(trimmed) {
+            Err(err) => {
+                eprintln!(
+                    "warning: {}: invalid pattern {}: {}",
+                    ignore_pth.display(),
+                    trimmed,
+                    err
+                );
+            }
+            Ok(pattern) => {
+                patterns.push(pattern);
+            }
+        }
+    }
+
+    patterns
+}
+
+/// is_ignored reports whether any ignore file between the walk root
+/// and the given file path excludes the file path.
+///
+/// Patterns containing a slash match against the path relative
+/// to the directory housing the ignore file.
+/// Other patterns match against the basename.
+fn is_ignored(
+    child_pth: &path::Path,
+    root_pth: &path::Path,
+    pattern_cache: &mut HashMap<path::PathBuf, Vec<glob::Pattern>>,
+) -> bool {
+    let filename: &str = child_pth
+        .file_name()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+
+    for dir in child_pth.ancestors().skip(1) {
+        if !dir.starts_with(root_pth) {
+            break;
+        }
+
+        let patterns: &Vec<glob::Pattern> = pattern_cache
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| load_ignore_patterns(dir));
+
+        let rel_string: String = child_pth
+            .strip_prefix(dir)
+            .map(|e| e.display().to_string())
+            .unwrap_or_default();
+
+        for pattern in patterns {
+            if pattern.as_str().contains('/') {
+                if pattern.matches(&rel_string) {
+                    return true;
+                }
+            } else if pattern.matches(filename) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// content_hash computes a 64 bit FNV-1a hash,
+/// keying lint result cache entries.
+fn content_hash(data: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for b in data {
+        hash ^= u64::from(*b);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash
+}
+
+/// cache_path names the cache entry for the given makefile.
+///
+/// Entries embed the unmake version,
+/// invalidating stale results when linter rules change.
+///
+/// Beyond file content, lint results depend on
+/// filename-derived metadata, such as include file detection.
+/// The key hashes those inputs alongside the content,
+/// so that byte-identical makefiles with distinct roles
+/// receive distinct entries.
+fn cache_path(
+    cache_dir: &str,
+    metadata: &inspect::Metadata,
+    makefile_str: &str,
+) -> path::PathBuf {
+    let key: String = format!(
+        "{}\0{}\0{}",
+        metadata.filename, metadata.is_include_file, makefile_str
+    );
+
+    path::Path::new(cache_dir).join(format!(
+        "{}-{:016x}.json",
+        env!("CARGO_PKG_VERSION"),
+        content_hash(key.as_bytes())
+    ))
+}
+
+/// extract_markdown_fences collects makefile snippets
+/// from fenced code blocks labeled "make" or "makefile"
+/// in a markdown document.
+///
+/// Returns the line number and byte offset
+/// of the start of each snippet in the document,
+/// alongside the snippet text.
+fn extract_markdown_fences(s: &str) -> Vec<(usize, usize, String)> {
+    let mut fences: Vec<(usize, usize, String)> = Vec::new();
+    let mut fence_option: Option<(usize, usize, String)> = None;
+    let mut line_offset: usize = 0;
+
+    for (i, line) in s.split_inclusive('\n').enumerate() {
+        let trimmed: &str = line.trim();
+
+        match &mut fence_option {
+            Some(fence) => {
+                if trimmed.starts_with("```") {
+                    fences.push(fence_option.take().unwrap());
+                } else {
+                    fence.2.push_str(line);
+                }
+            }
+            None => {
+                let label: &str = trimmed
+                    .strip_prefix("```")
+                    .unwrap_or("")
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or("");
+
+                if ["make", "makefile"].contains(&label.to_lowercase().as_str()) {
+                    fence_option = Some((2 + i, line_offset + line.len(), String::new()));
+                }
+            }
+        }
+
+        line_offset += line.len();
+    }
+
+    fences
+}
+
+/// expand_globs interprets glob patterns in path arguments,
+/// for shells that do not expand globs natively, such as cmd.exe.
+///
+/// Arguments free of glob metacharacters pass through unaltered.
+fn expand_globs(pth_strings: Vec<String>) -> Result<Vec<String>, String> {
+    let mut expansions: Vec<String> = Vec::new();
+
+    for pth_string in pth_strings {
+        if !pth_string.contains(['*', '?', '[']) {
+            expansions.push(pth_string);
+            continue;
+        }
+
+        let paths = glob::glob(&pth_string)
+            .map_err(|err| format!("error: {}: {}", pth_string, err))?;
+
+        let mut found_match: bool = false;
+
+        for path_result in paths {
+            let path: path::PathBuf =
+                path_result.map_err(|err| format!("error: {}: {}", pth_string, err))?;
+            expansions.push(path.display().to_string());
+            found_match = true;
+        }
+
+        if !found_match {
+            return Err(format!("error: {}: no matches for pattern", pth_string));
+        }
+    }
+
+    Ok(expansions)
+}
+
+/// collect_changed_paths queries git for files changed since the given ref,
+/// returning canonicalized paths.
+///
+/// Deleted files naturally drop out, as they no longer canonicalize.
+fn collect_changed_paths(git_ref: &str) -> Result<HashSet<path::PathBuf>, String> {
+    let root_output: process::Output = process::Command::new("git")
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .map_err(|err| format!("error: unable to run git: {}", err))?;
+
+    if !root_output.status.success() {
+        return Err(format!(
+            "error: git rev-parse failed: {}",
+            String::from_utf8_lossy(&root_output.stderr).trim()
+        ));
+    }
+
+    let repo_root: path::PathBuf = path::PathBuf::from(
+        String::from_utf8_lossy(&root_output.stdout)
+            .trim()
+            .to_string(),
+    );
+
+    let diff_output: process::Output = process::Command::new("git")
+        .args(["diff", "--name-only", git_ref])
+        .output()
+        .map_err(|err| format!("error: unable to run git: {}", err))?;
+
+    if !diff_output.status.success() {
+        return Err(format!(
+            "error: git diff --name-only {} failed: {}",
+            git_ref,
+            String::from_utf8_lossy(&diff_output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&diff_output.stdout)
+        .lines()
+        .map(|e| e.trim())
+        .filter(|e| !e.is_empty())
+        .filter_map(|e| repo_root.join(e).canonicalize().ok())
+        .collect())
+}
+
 /// CLI entrypoint
 fn main() {
     let brief: String = format!(
@@ -31,9 +357,103 @@ fn main() {
 
     let mut opts: getopts::Options = getopts::Options::new();
     opts.optopt("i", "inspect", "summarize file details", "<makefile>");
+    opts.optopt("e", "explain", "describe a rule in detail", "<rule id>");
+    opts.optopt(
+        "",
+        "stdin-filename",
+        "virtual filename for \"-\" stdin input, enabling filename-derived checks",
+        "<name>",
+    );
     opts.optflag("d", "debug", "emit additional logs");
+    opts.optflag(
+        "s",
+        "strict",
+        "lint include files as standalone makefiles",
+    );
     opts.optflag("h", "help", "print usage info");
     opts.optflag("l", "list", "list makefile paths");
+    opts.optopt(
+        "f",
+        "format",
+        "warning output format (plain, json, sarif)",
+        "<fmt>",
+    );
+    opts.optopt(
+        "",
+        "max-include-depth",
+        "include chain depth threshold (default 8)",
+        "<n>",
+    );
+    opts.optopt(
+        "o",
+        "output",
+        "write formatted results to a file instead of stdout",
+        "<path>",
+    );
+    opts.optflag("", "verbose", "summarize warning counts by rule id");
+    opts.optflag("", "profile", "report per-check timing to stderr");
+    opts.optopt(
+        "",
+        "config",
+        "load a rule configuration file",
+        "<path>",
+    );
+    opts.optflag(
+        "c",
+        "count",
+        "print only the total warning count",
+    );
+    opts.optflag(
+        "",
+        "skip-generated",
+        "skip machine-generated makefiles (default)",
+    );
+    opts.optflag(
+        "",
+        "no-skip-generated",
+        "lint machine-generated makefiles",
+    );
+    opts.optopt(
+        "",
+        "baseline",
+        "suppress warnings recorded in a baseline file",
+        "<path>",
+    );
+    opts.optflag(
+        "",
+        "write-baseline",
+        "record current warnings to the baseline file",
+    );
+    opts.optflag(
+        "",
+        "from-markdown",
+        "lint fenced make code blocks in markdown documents",
+    );
+    opts.optopt(
+        "",
+        "cache-dir",
+        "cache lint results by content hash in a directory",
+        "<path>",
+    );
+    opts.optflag("", "no-cache", "disable lint result caching");
+    opts.optopt(
+        "",
+        "paths-from",
+        "read makefile paths from a file, or \"-\" for stdin",
+        "<path>",
+    );
+    opts.optflag("", "null", "read null delimited paths with --paths-from");
+    opts.optopt(
+        "",
+        "since",
+        "lint only makefiles changed since a git ref",
+        "<ref>",
+    );
+    opts.optflag(
+        "0",
+        "files-only",
+        "emit null delimited makefile paths without linting",
+    );
     opts.optflag("", "print0", "null delimit paths");
     opts.optflag(
         "n",
@@ -55,10 +475,91 @@ fn main() {
     }
 
     let debug: bool = optmatches.opt_present("d");
+    let strict: bool = optmatches.opt_present("s");
+    let verbose: bool = optmatches.opt_present("verbose");
+    let profile: bool = optmatches.opt_present("profile");
+
+    let rule_config: config::Config = match optmatches.opt_str("config") {
+        Some(config_pth_string) => config::Config::load(path::Path::new(&config_pth_string))
+            .map_err(|err| die!(1; err))
+            .unwrap(),
+        None => config::Config::default(),
+    };
+    let format: warnings::OutputFormat = optmatches
+        .opt_str("f")
+        .unwrap_or("plain".to_string())
+        .parse()
+        .map_err(|err| die!(1; format!("error: {}", err)))
+        .unwrap();
+
+    let count_only: bool = optmatches.opt_present("c");
+
+    if count_only && optmatches.opt_present("f") {
+        die!(1; "error: --count is mutually exclusive with --format");
+    }
+
+    let output_pth_option: Option<String> = optmatches.opt_str("o");
+    let max_include_depth: usize = optmatches
+        .opt_str("max-include-depth")
+        .map(|e| {
+            e.parse()
+                .die(&format!("error: invalid include depth: {}", e))
+        })
+        .unwrap_or(DEFAULT_MAX_INCLUDE_DEPTH);
+    let stdin_filename: String = optmatches
+        .opt_str("stdin-filename")
+        .unwrap_or("-".to_string());
+    let skip_generated: bool = !optmatches.opt_present("no-skip-generated");
+    let from_markdown: bool = optmatches.opt_present("from-markdown");
+    let baseline_pth_option: Option<String> = optmatches.opt_str("baseline");
+    let write_baseline: bool = optmatches.opt_present("write-baseline");
+
+    if write_baseline && baseline_pth_option.is_none() {
+        die!(1; "error: --write-baseline requires --baseline");
+    }
+
+    let cache_dir_option: Option<String> = match optmatches.opt_present("no-cache") {
+        true => None,
+        false => optmatches.opt_str("cache-dir"),
+    };
+
+    if let Some(cache_dir) = &cache_dir_option {
+        fs::create_dir_all(cache_dir)
+            .map_err(|err| die!(format!("error: {}: {}", cache_dir, err)))
+            .unwrap();
+    }
+    let files_only: bool = optmatches.opt_present("0");
     let list_makefile_paths: bool = optmatches.opt_present("l");
-    let null_delimit_paths: bool = optmatches.opt_present("print0");
+    let null_delimit_paths: bool = optmatches.opt_present("print0") || files_only;
     let process_dry_run: bool = optmatches.opt_present("n");
 
+    if optmatches.opt_present("e") {
+        let rule_id_string: String = optmatches.opt_str("e").die(&usage);
+
+        match warnings::describe_rule(&rule_id_string) {
+            Some(message) => {
+                println!("{}", message);
+
+                if let Some(explanation) = warnings::explain_rule(&rule_id_string) {
+                    println!("\n{}", explanation);
+                }
+
+                println!("\ndocumentation: {}", warnings::rule_url(&rule_id_string));
+                die!(0);
+            }
+            None => {
+                eprintln!("error: unknown rule id: {}", rule_id_string);
+                eprintln!("valid rule ids:");
+
+                for message in warnings::RULE_MESSAGES.iter() {
+                    eprintln!("{}", warnings::rule_id(message));
+                }
+
+                die!(1);
+            }
+        }
+    }
+
     if optmatches.opt_present("i") {
         let pth_string = optmatches.opt_str("i").die(&usage);
         let pth: &path::Path = path::Path::new(&pth_string);
@@ -67,20 +568,167 @@ fn main() {
         die!(0);
     }
 
-    let pth_strings: Vec<String> = optmatches.free;
+    let paths_from_option: Option<String> = optmatches.opt_str("paths-from");
+    let since_option: Option<String> = optmatches.opt_str("since");
+    let null_delimited_paths: bool = optmatches.opt_present("null");
+    let mut pth_strings: Vec<String> = expand_globs(optmatches.free)
+        .map_err(|err| die!(err))
+        .unwrap();
+
+    if let Some(paths_from) = paths_from_option {
+        let paths_str: String = if paths_from == "-" {
+            let mut s: String = String::new();
+            io::Read::read_to_string(&mut io::stdin(), &mut s)
+                .die("error: unable to read stdin");
+            s
+        } else {
+            fs::read_to_string(&paths_from)
+                .map_err(|err| die!(format!("error: {}: {}", paths_from, err)))
+                .unwrap()
+        };
+
+        let delimiter: char = if null_delimited_paths { '\0' } else { '\n' };
+
+        pth_strings.extend(
+            paths_str
+                .split(delimiter)
+                .map(|e| e.trim_end_matches('\r'))
+                .filter(|e| !e.is_empty())
+                .map(|e| e.to_string()),
+        );
+    }
 
     if pth_strings.is_empty() {
         die!(1; usage);
     }
 
+    let changed_pths_option: Option<HashSet<path::PathBuf>> = since_option.map(|git_ref| {
+        collect_changed_paths(&git_ref)
+            .map_err(|err| die!(err))
+            .unwrap()
+    });
+
     let mut found_quirk = false;
+    let mut matched_makefile_count: usize = 0;
+    let mut skipped_generated_count: usize = 0;
     let mut ws: Vec<warnings::Warning> = Vec::new();
+    let mut check_timings: HashMap<&'static str, time::Duration> = HashMap::new();
+
+    if pth_strings.contains(&"-".to_string()) {
+        let mut makefile_str: String = String::new();
+        io::Read::read_to_string(&mut io::stdin(), &mut makefile_str)
+            .die("error: unable to read stdin");
+
+        let mut metadata: inspect::Metadata =
+            inspect::analyze_virtual(&stdin_filename, &makefile_str);
+
+        if strict {
+            metadata.is_include_file = false;
+        }
+
+        if !metadata.is_makefile || metadata.build_system != "make" {
+            if debug {
+                eprintln!("debug: skipping stdin: non-strict implementation");
+            }
+        } else {
+            let lint_result: Result<Vec<warnings::Warning>, String> = if profile {
+                warnings::lint_timed(&metadata, &makefile_str, &mut check_timings)
+            } else {
+                warnings::lint(&metadata, &makefile_str)
+            };
+
+            match lint_result {
+                Err(_) => {
+                    found_quirk = true;
+                    let (ws2, errors) = warnings::lint_recover(&metadata, &makefile_str);
+
+                    for error in errors {
+                        println!("{}", error);
+                    }
+
+                    ws.extend(warnings::apply_config(
+                        &metadata,
+                        &makefile_str,
+                        &rule_config,
+                        ws2,
+                    ));
+                }
+                Ok(ws2) => {
+                    ws.extend(warnings::apply_config(
+                        &metadata,
+                        &makefile_str,
+                        &rule_config,
+                        ws2,
+                    ));
+                }
+            }
+        }
+    }
 
     let cwd: std::path::PathBuf =
         env::current_dir().die("error: unable to query current working directory");
 
     let mut action = |p: &path::Path| {
+        if let Some(changed_pths) = &changed_pths_option {
+            match p.canonicalize() {
+                Ok(canonical) if changed_pths.contains(&canonical) => {}
+                _ => return,
+            }
+        }
+
         let pth_string: String = p.display().to_string();
+
+        if from_markdown {
+            if p.extension().and_then(|e| e.to_str()) != Some("md") {
+                return;
+            }
+
+            let document_str: String = match fs::read_to_string(p) {
+                Err(err) => {
+                    found_quirk = true;
+                    println!("error: {}: {}", p.display(), err);
+                    return;
+                }
+                Ok(s) => s,
+            };
+
+            for (fence_line, fence_offset, fence_str) in extract_markdown_fences(&document_str) {
+                let mut metadata: inspect::Metadata =
+                    inspect::analyze_virtual("makefile", &fence_str);
+                metadata.path = pth_string.clone();
+
+                if strict {
+                    metadata.is_include_file = false;
+                }
+
+                let lint_result: Result<Vec<warnings::Warning>, String> = if profile {
+                    warnings::lint_timed(&metadata, &fence_str, &mut check_timings)
+                } else {
+                    warnings::lint(&metadata, &fence_str)
+                };
+
+                match lint_result {
+                    Err(err) => {
+                        found_quirk = true;
+                        println!("{}", err);
+                    }
+                    Ok(ws2) => {
+                        ws.extend(
+                            warnings::apply_config(&metadata, &fence_str, &rule_config, ws2)
+                                .into_iter()
+                                .map(|mut e| {
+                                    e.line += fence_line - 1;
+                                    e.offset += fence_offset;
+                                    e
+                                }),
+                        );
+                    }
+                }
+            }
+
+            return;
+        }
+
         let metadata_result: Result<unmake::inspect::Metadata, String> =
             unmake::inspect::analyze(p);
 
@@ -90,13 +738,19 @@ fn main() {
             return;
         }
 
-        let metadata: inspect::Metadata = metadata_result.unwrap();
+        let mut metadata: inspect::Metadata = metadata_result.unwrap();
+
+        if strict {
+            metadata.is_include_file = false;
+        }
 
         if !metadata.is_makefile {
             return;
         }
 
-        if metadata.is_machine_generated {
+        if metadata.is_machine_generated && skip_generated {
+            skipped_generated_count += 1;
+
             if debug {
                 eprintln!(
                     "debug: skipping {}: likely machine-generated by {}",
@@ -107,7 +761,15 @@ fn main() {
             return;
         }
 
-        if list_makefile_paths {
+        if metadata.is_machine_generated {
+            // Machine-generated detection only fires for make flavored files,
+            // after overwriting build_system with the parent build system.
+            metadata.build_system = "make".to_string();
+        }
+
+        if list_makefile_paths || files_only {
+            matched_makefile_count += 1;
+
             if null_delimit_paths {
                 print!("{}\0", pth_string);
             } else {
@@ -198,28 +860,108 @@ fn main() {
 
         let makefile_str: &str = &makefile_str_result.unwrap();
 
-        let ws2_result: Result<Vec<warnings::Warning>, String> =
-            warnings::lint(&metadata, makefile_str);
+        let cache_pth_option: Option<path::PathBuf> = cache_dir_option
+            .as_ref()
+            .map(|cache_dir| cache_path(cache_dir, &metadata, makefile_str));
 
-        if let Err(err) = ws2_result {
-            found_quirk = true;
-            println!("{}", err);
-            return;
-        }
+        let cached_ws_option: Option<Vec<warnings::Warning>> = cache_pth_option
+            .as_ref()
+            .and_then(|cache_pth| fs::read_to_string(cache_pth).ok())
+            .and_then(|cache_str| serde_json::from_str(&cache_str).ok());
+
+        match cached_ws_option {
+            Some(cached_ws) => {
+                if debug {
+                    eprintln!("debug: cache hit for {}", pth_string);
+                }
 
-        let ws2: Vec<warnings::Warning> = ws2_result.unwrap();
+                let ws2: Vec<warnings::Warning> = cached_ws
+                    .into_iter()
+                    .map(|mut e| {
+                        e.path = pth_string.clone();
+                        e
+                    })
+                    .collect();
 
-        if !ws2.is_empty() {
-            found_quirk = true;
+                ws.extend(warnings::apply_config(
+                    &metadata,
+                    makefile_str,
+                    &rule_config,
+                    ws2,
+                ));
+            }
+            None => {
+                let ws2_result: Result<Vec<warnings::Warning>, String> = if profile {
+                    warnings::lint_timed(&metadata, makefile_str, &mut check_timings)
+                } else {
+                    warnings::lint(&metadata, makefile_str)
+                };
+
+                if ws2_result.is_err() {
+                    found_quirk = true;
+                    let (ws2, errors) = warnings::lint_recover(&metadata, makefile_str);
+
+                    for error in errors {
+                        println!("{}", error);
+                    }
+
+                    ws.extend(warnings::apply_config(
+                        &metadata,
+                        makefile_str,
+                        &rule_config,
+                        ws2,
+                    ));
+                    return;
+                }
+
+                let ws2: Vec<warnings::Warning> = ws2_result.unwrap();
+
+                // Cache entries record configuration-independent results,
+                // so that entries survive rule configuration changes.
+                if let Some(cache_pth) = &cache_pth_option {
+                    if let Ok(cache_str) = serde_json::to_string(&ws2) {
+                        if fs::write(cache_pth, cache_str).is_err() && debug {
+                            eprintln!("debug: unable to cache results for {}", pth_string);
+                        }
+                    }
+                }
+
+                ws.extend(warnings::apply_config(
+                    &metadata,
+                    makefile_str,
+                    &rule_config,
+                    ws2,
+                ));
+            }
         }
 
-        ws.extend(ws2);
+        if !metadata.is_include_file {
+            let mut include_stack: Vec<path::PathBuf> = Vec::new();
+            let mut include_reports: Vec<String> = Vec::new();
+            scan_include_chain(
+                p,
+                max_include_depth,
+                &mut include_stack,
+                &mut include_reports,
+            );
+
+            for report in include_reports {
+                found_quirk = true;
+                println!("{}", report);
+            }
+        }
     };
 
     for pth_string in pth_strings {
+        if pth_string == "-" {
+            continue;
+        }
+
         let pth: &path::Path = path::Path::new(&pth_string);
 
         if pth.is_dir() {
+            let mut ignore_pattern_cache: HashMap<path::PathBuf, Vec<glob::Pattern>> =
+                HashMap::new();
             let walker = walkdir::WalkDir::new(pth)
                 .sort_by_file_name()
                 .into_iter()
@@ -235,6 +977,18 @@ fn main() {
                     continue;
                 }
 
+                if is_ignored(child_pth, pth, &mut ignore_pattern_cache) {
+                    if debug {
+                        eprintln!(
+                            "debug: skipping {}: excluded by {}",
+                            child_pth.display(),
+                            IGNORE_FILENAME
+                        );
+                    }
+
+                    continue;
+                }
+
                 action(child_pth);
             }
         } else {
@@ -242,10 +996,132 @@ fn main() {
         }
     }
 
-    ws.sort_by(|a, b| a.line.cmp(&b.line));
+    if files_only {
+        if matched_makefile_count > 0 {
+            die!(0);
+        }
+
+        die!(1);
+    }
+
+    ws.retain(|e| rule_config.allows(&e.message));
+
+    if let Some(baseline_pth) = &baseline_pth_option {
+        if write_baseline {
+            let mut entries: Vec<String> = ws
+                .iter()
+                .map(|e| format!("{}:{}:{}\n", e.path, e.line, warnings::rule_id(&e.message)))
+                .collect();
+            entries.sort();
+            entries.dedup();
+
+            fs::write(baseline_pth, entries.concat())
+                .map_err(|err| die!(format!("error: {}: {}", baseline_pth, err)))
+                .unwrap();
+            eprintln!(
+                "wrote {} baseline entries to {}",
+                entries.len(),
+                baseline_pth
+            );
+
+            if found_quirk {
+                die!(1);
+            }
+
+            die!(0);
+        }
+
+        let baseline_str: String = fs::read_to_string(baseline_pth)
+            .map_err(|err| die!(format!("error: {}: {}", baseline_pth, err)))
+            .unwrap();
+
+        let baseline_entries: HashSet<(String, usize, String)> = baseline_str
+            .lines()
+            .map(|e| e.trim())
+            .filter(|e| !e.is_empty() && !e.starts_with('#'))
+            .filter_map(|e| {
+                let mut fields = e.rsplitn(3, ':');
+                let rule_id_string: String = fields.next()?.to_string();
+                let line: usize = fields.next()?.parse().ok()?;
+                let path: String = fields.next()?.to_string();
+                Some((path, line, rule_id_string))
+            })
+            .collect();
+
+        ws.retain(|e| {
+            !baseline_entries.contains(&(
+                e.path.clone(),
+                e.line,
+                warnings::rule_id(&e.message).to_string(),
+            ))
+        });
+    }
+
+    if !ws.is_empty() {
+        found_quirk = true;
+    }
+
+    ws.sort_by_key(|e| e.line);
+
+    let mut file_counts: HashMap<&String, usize> = HashMap::new();
+    let mut rule_counts: HashMap<&str, usize> = HashMap::new();
+
+    for w in &ws {
+        *file_counts.entry(&w.path).or_insert(0) += 1;
+        *rule_counts.entry(warnings::rule_id(&w.message)).or_insert(0) += 1;
+    }
+
+    let rendered: String = if count_only {
+        format!("{}\n", ws.len())
+    } else {
+        warnings::format_warnings(&ws, format)
+    };
+
+    match &output_pth_option {
+        Some(output_pth) => {
+            fs::write(output_pth, &rendered)
+                .map_err(|err| die!(format!("error: {}: {}", output_pth, err)))
+                .unwrap();
+        }
+        None => {
+            print!("{}", rendered);
+        }
+    }
+
+    if format == warnings::OutputFormat::Plain
+        && !count_only
+        && !list_makefile_paths
+        && !process_dry_run
+    {
+        eprintln!("{} warnings across {} files", ws.len(), file_counts.len());
+
+        if skipped_generated_count > 0 {
+            eprintln!(
+                "note: skipped {} machine-generated makefile(s); rerun with --no-skip-generated to lint them",
+                skipped_generated_count
+            );
+        }
+
+        if verbose {
+            let mut rule_count_entries: Vec<(&str, usize)> = rule_counts.into_iter().collect();
+            rule_count_entries.sort();
+
+            for (id, count) in rule_count_entries {
+                eprintln!("{}: {} {}", id, count, warnings::rule_url(id));
+            }
+        }
+    }
+
+    if profile {
+        let mut timing_entries: Vec<(&'static str, time::Duration)> =
+            check_timings.into_iter().collect();
+        timing_entries.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
 
-    for w in ws {
-        println!("{}", w);
+        eprintln!("profile: cumulative duration per check");
+
+        for (name, duration) in timing_entries {
+            eprintln!("{:>14} {}", format!("{:?}", duration), name);
+        }
     }
 
     if found_quirk {
@@ -0,0 +1,143 @@
+//! config loads optional linter rule configurations.
+
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::{Deserialize, Serialize};
+use std::fs;
+use std::path;
+use warnings;
+
+/// Config models an unmake rule configuration,
+/// merged over built-in defaults.
+///
+/// Config files are JSON documents, e.g.:
+///
+/// ```json
+/// { "disable": ["PHONY_TARGET"], "enable": ["TODO_COMMENT"] }
+/// ```
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// disable collects rule identifiers to skip during linting.
+    pub disable: Vec<String>,
+
+    /// enable collects opt-in rule identifiers
+    /// to activate during linting,
+    /// drawn from [warnings::OPT_IN_CHECKS] and [warnings::OPT_IN_TEXT_CHECKS].
+    pub enable: Vec<String>,
+
+    /// default_rule_name optionally overrides the name that RULE_ALL
+    /// accepts for the first non-special, default rule,
+    /// e.g. "help" for self-documenting makefiles.
+    ///
+    /// Defaults to [warnings::DEFAULT_RULE_NAME].
+    pub default_rule_name: Option<String>,
+}
+
+impl Config {
+    /// load parses a Config from the JSON file at the given path.
+    pub fn load(pth: &path::Path) -> Result<Config, String> {
+        let config_str: String = fs::read_to_string(pth)
+            .map_err(|err| format!("error: {}: {}", pth.display(), err))?;
+
+        let config: Config = serde_json::from_str(&config_str)
+            .map_err(|err| format!("error: {}: {}", pth.display(), err))?;
+
+        config
+            .validate()
+            .map_err(|err| format!("error: {}: {}", pth.display(), err))?;
+
+        Ok(config)
+    }
+
+    /// validate reports configuration mistakes,
+    /// such as enable entries naming no known opt-in rule.
+    pub fn validate(&self) -> Result<(), String> {
+        for id in &self.enable {
+            if !warnings::is_opt_in_rule(id) {
+                return Err(format!("unknown opt-in rule id: {}", id));
+            }
+        }
+
+        if self.enables("PHONY_SPLIT") && self.enables("PHONY_CONSOLIDATED") {
+            return Err(
+                "PHONY_SPLIT and PHONY_CONSOLIDATED enforce contradictory .PHONY styles; enable at most one".to_string(),
+            );
+        }
+
+        if let Some(default_rule_name) = &self.default_rule_name {
+            if default_rule_name.is_empty() {
+                return Err("default_rule_name may not be empty".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// allows reports whether the given warning message
+    /// passes this configuration.
+    pub fn allows(&self, message: &str) -> bool {
+        !self
+            .disable
+            .iter()
+            .any(|id| id == warnings::rule_id(message))
+    }
+
+    /// enables reports whether this configuration
+    /// activates the given opt-in rule id.
+    pub fn enables(&self, id: &str) -> bool {
+        self.enable.iter().any(|e| e == id)
+    }
+}
+
+#[test]
+fn test_config() {
+    let config: Config =
+        serde_json::from_str(r#"{ "disable": ["PHONY_TARGET"] }"#).unwrap();
+
+    assert!(!config.allows("PHONY_TARGET: mark common artifactless rules as .PHONY"));
+    assert!(config.allows("NO_RULES: declare at least one rule"));
+
+    let default_config: Config = Config::default();
+    assert!(default_config.allows("PHONY_TARGET: mark common artifactless rules as .PHONY"));
+    assert!(!default_config.enables("TODO_COMMENT"));
+
+    assert!(serde_json::from_str::<Config>("{}").unwrap().disable.is_empty());
+    assert!(Config::load(path::Path::new("no/such/config.json")).is_err());
+
+    let enable_config: Config =
+        serde_json::from_str(r#"{ "enable": ["TODO_COMMENT"] }"#).unwrap();
+    assert!(enable_config.validate().is_ok());
+    assert!(enable_config.enables("TODO_COMMENT"));
+    assert!(!enable_config.enables("PHONY_SPLIT"));
+
+    assert!(serde_json::from_str::<Config>(r#"{ "enable": ["NO_SUCH_RULE"] }"#)
+        .unwrap()
+        .validate()
+        .is_err());
+
+    assert!(serde_json::from_str::<Config>(r#"{ "enabled": ["TODO_COMMENT"] }"#).is_err());
+
+    assert!(serde_json::from_str::<Config>(r#"{ "enable": ["PHONY_SPLIT"] }"#)
+        .unwrap()
+        .validate()
+        .is_ok());
+
+    assert!(
+        serde_json::from_str::<Config>(r#"{ "enable": ["PHONY_SPLIT", "PHONY_CONSOLIDATED"] }"#)
+            .unwrap()
+            .validate()
+            .is_err()
+    );
+
+    let help_config: Config =
+        serde_json::from_str(r#"{ "default_rule_name": "help" }"#).unwrap();
+    assert!(help_config.validate().is_ok());
+    assert_eq!(help_config.default_rule_name, Some("help".to_string()));
+
+    assert!(serde_json::from_str::<Config>(r#"{ "default_rule_name": "" }"#)
+        .unwrap()
+        .validate()
+        .is_err());
+}
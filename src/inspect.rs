@@ -13,6 +13,8 @@ use std::path;
 
 lazy_static::lazy_static! {
     /// LOWER_FILENAMES_TO_IMPLEMENTATIONS maps common filenames to make implementation flavors.
+    ///
+    /// Stable API: downstream integrations may query this table.
     pub static ref LOWER_FILENAMES_TO_IMPLEMENTATIONS: HashMap<String, String> = vec![
         ("bsdmakefile", "bmake"),
         ("gnumakefile", "gmake"),
@@ -23,6 +25,8 @@ lazy_static::lazy_static! {
     .collect::<HashMap<String, String>>();
 
     /// LOWER_FILE_EXTENSIONS_TO_IMPLEMENTATIONS maps common file extensions to make implementation flavors.
+    ///
+    /// Stable API: downstream integrations may query this table.
     pub static ref LOWER_FILE_EXTENSIONS_TO_IMPLEMENTATIONS: HashMap<String, String> = vec![
         ("bsdmakefile", "bmake"),
         ("gnumakefile", "gmake"),
@@ -47,6 +51,8 @@ lazy_static::lazy_static! {
 
     /// LOWER_INCLUDE_FILENAME_PATTERN matches common filenames for makefiles intended
     /// for inclusion into other makefiles.
+    ///
+    /// Stable API: downstream integrations may query this pattern.
     pub static ref LOWER_INCLUDE_FILENAME_PATTERN: regex::Regex = regex::Regex::new(r"^sys\.mk|(.*\.)?include\.(bsdmakefile|gnumakefile|makefile|mk)$").unwrap();
 }
 
@@ -127,6 +133,64 @@ impl fmt::Display for Metadata {
     }
 }
 
+/// analyze_virtual summarizes high level attributes of makefile content
+/// received apart from the file system, such as a stream on stdin.
+///
+/// The virtual path feeds filename-derived checks,
+/// for example reporting a buffer named "Makefile" to MAKEFILE_PRECEDENCE.
+/// Pass "-" to skip filename-derived checks,
+/// assuming a lintable POSIX makefile.
+pub fn analyze_virtual(pth_string: &str, makefile_str: &str) -> Metadata {
+    let mut metadata: Metadata = Metadata::new();
+    metadata.path = pth_string.to_string();
+
+    if pth_string == "-" {
+        metadata.filename = pth_string.to_string();
+        metadata.is_makefile = true;
+        metadata.build_system = "make".to_string();
+    } else {
+        let pth: &path::Path = path::Path::new(pth_string);
+
+        metadata.filename = pth
+            .file_name()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_string();
+
+        let filename_lower: String = metadata.filename.to_lowercase();
+        let file_extension_lower: String = pth
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or("")
+            .to_string()
+            .to_lowercase();
+
+        if let Some(implementation) = LOWER_FILENAMES_TO_IMPLEMENTATIONS.get(&filename_lower) {
+            metadata.is_makefile = true;
+            metadata.build_system = implementation.to_string();
+        }
+
+        if let Some(implementation) =
+            LOWER_FILE_EXTENSIONS_TO_IMPLEMENTATIONS.get(&file_extension_lower)
+        {
+            metadata.is_makefile = true;
+            metadata.build_system = implementation.to_string();
+        }
+
+        metadata.is_include_file = LOWER_INCLUDE_FILENAME_PATTERN.is_match(&filename_lower);
+    }
+
+    metadata.is_empty = makefile_str.is_empty();
+
+    if !metadata.is_empty {
+        metadata.lines = 1 + makefile_str.matches('\n').count();
+        let last_char: char = makefile_str.chars().last().unwrap_or(' ');
+        metadata.has_final_eol = last_char == '\n';
+    }
+
+    metadata
+}
+
 /// metadata summaries high level attributes of a file path,
 /// such as whether the file path appears to represent a conventional makefile,
 /// whether the makefile is likely to use extensions beyond pure POSIX,
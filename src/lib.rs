@@ -1,5 +1,10 @@
 //! unmake provides predicates for analyzing makefiles.
 
 pub mod ast;
+pub mod config;
 pub mod inspect;
+
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 pub mod warnings;
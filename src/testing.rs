@@ -0,0 +1,29 @@
+//! testing provides helpers for exercising unmake linter behavior
+//! from downstream test suites.
+//!
+//! Enable with the `testing` feature.
+
+use inspect;
+use warnings;
+
+pub use warnings::mock_md;
+
+/// lint_rule_ids lints a makefile string against simulated Metadata,
+/// reducing any warnings to their rule ids.
+pub fn lint_rule_ids(makefile: &str) -> Result<Vec<String>, String> {
+    let metadata: inspect::Metadata = mock_md("-");
+
+    Ok(warnings::lint(&metadata, makefile)?
+        .into_iter()
+        .map(|e| warnings::rule_id(&e.message).to_string())
+        .collect())
+}
+
+#[test]
+fn test_lint_rule_ids() {
+    assert!(lint_rule_ids(".POSIX:\nMAKEFLAGS = -j\nall:\n\techo \"Hello World!\"\n")
+        .unwrap()
+        .contains(&"UB_MAKEFLAGS_MACRO".to_string()));
+
+    assert!(lint_rule_ids("fo:::o\n").is_err());
+}
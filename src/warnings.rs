@@ -1,9 +1,24 @@
 //! warnings generates makefile recommendations.
 
+extern crate serde;
+extern crate serde_json;
+
+use self::serde::{Deserialize, Serialize};
 use ast;
+use config;
 use inspect;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::str;
+use std::time;
+
+/// named_checks constructs (name, check) pairs,
+/// recording each check function name for profiling reports.
+macro_rules! named_checks {
+    ($t:ty, $($f:ident),+ $(,)?) => {
+        vec![$((stringify!($f), $f as $t)),+]
+    };
+}
 
 lazy_static::lazy_static! {
     /// WD_COMMANDS collects common commands for modifying a shell's current working directory.
@@ -19,6 +34,12 @@ lazy_static::lazy_static! {
         "^all|lint|install|uninstall|publish|(test.*)|(clean.*)$"
     ).unwrap();
 
+    /// LOWER_CLEAN_TARGETS_PATTERN matches the clean family of target names,
+    /// specified in lowercase.
+    pub static ref LOWER_CLEAN_TARGETS_PATTERN: regex::Regex = regex::Regex::new(
+        "^clean.*$"
+    ).unwrap();
+
     /// COMMAND_PREFIX_PATTERN matches commands with prefixes.
     pub static ref COMMAND_PREFIX_PATTERN: regex::Regex = regex::Regex::new(r"^(?P<prefix>[-+@]+)").unwrap();
 
@@ -33,645 +54,7425 @@ lazy_static::lazy_static! {
     /// RESERVED_TARGET_PATTERN matches targets reserved either for POSIX use, or for extensions.
     pub static ref RESERVED_TARGET_PATTERN: regex::Regex = regex::Regex::new(r"^.[A-Z]+").unwrap();
 
+    /// SHELL_VARIABLE_ASSIGNMENT_PATTERN matches commands leading with a shell variable assignment.
+    pub static ref SHELL_VARIABLE_ASSIGNMENT_PATTERN: regex::Regex = regex::Regex::new(
+        r"^(?:export\s+)?(?P<name>[A-Za-z_][A-Za-z0-9_]*)="
+    ).unwrap();
+
     /// WARNING_DEFAULT_PATH assumes stdin (unimplemented).
     static ref WARNING_DEFAULT_PATH: String = "-".to_string();
 
-    /// CHECKS collects the set of available high level makefile scans.
-    pub static ref CHECKS: Vec<Check> = vec![
+    /// NAMED_CHECKS collects the set of available high level makefile scans,
+    /// paired with their function names for profiling reports.
+    pub static ref NAMED_CHECKS: Vec<(&'static str, Check)> = named_checks![Check,
         check_ub_late_posix_marker,
         check_ub_ambiguous_include,
         check_ub_makeflags_assignment,
         check_ub_shell_macro,
         check_strict_posix,
         check_implementation_defined_target,
+        check_shell_metacharacter_target,
+        check_pattern_rule,
+        check_gnu_vpath,
+        check_order_only_prereq,
+        check_double_colon_rule,
         check_makefile_precedence,
         check_curdir_assignment_nop,
+        check_readonly_macro_assignment,
+        check_makecmdgoals_expansion,
+        check_pwd_expansion,
+        check_pipeline_masking,
+        check_nonportable_echo_flag,
+        check_interactive_command,
+        check_nonportable_function,
+        check_wildcard_expansion,
+        check_shell_assignment,
+        check_append_undefined_macro,
+        check_recursive_macro,
+        check_unused_macro,
+        check_undefined_macro,
         check_wd_nop,
+        check_make_after_cd,
         check_wait_nop,
         check_phony_nop,
+        check_default_nop,
+        check_phony_path,
         check_redundant_notparallel_wait,
+        check_linear_wait,
         check_redundant_silent_at,
         check_redundant_ignore_minus,
+        check_layered_silence,
         check_global_ignore,
+        check_soften_clean,
         check_simplify_at,
         check_simplify_minus,
+        check_ignored_sole_command,
+        check_inconsistent_silence,
         check_command_comment,
+        check_shell_comment_only_command,
         check_phony_target,
+        check_dangling_special_prerequisite,
+        check_no_op_rule,
+        check_absolute_include,
+        check_late_include,
+        check_duplicate_prerequisite,
+        check_self_dependency,
+        check_self_modification,
+        check_suffixes_fragmentation,
         check_repeated_command_prefix,
         check_blank_command,
         check_whitespace_leading_command,
+        check_command_length,
+        check_excessive_continuation,
+        check_non_ascii_name,
+        check_empty_makefile,
         check_no_rules,
+        check_export_special_target,
+        check_oneshell,
         check_reserved_target,
         check_rule_all,
         check_final_eol,
     ];
-}
 
-/// Check implements a linter scan.
-pub type Check = fn(&inspect::Metadata, &[ast::Gem]) -> Vec<Warning>;
+    /// CHECKS collects the set of available high level makefile scans.
+    pub static ref CHECKS: Vec<Check> = NAMED_CHECKS.iter().map(|e| e.1).collect();
+
+    /// NAMED_TEXT_CHECKS collects the set of available raw text makefile scans,
+    /// paired with their function names for profiling reports.
+    pub static ref NAMED_TEXT_CHECKS: Vec<(&'static str, TextCheck)> = named_checks![TextCheck,
+        check_tab_field_separator,
+        check_windows_path_separator,
+        check_unterminated_macro_expansion,
+        check_inconsistent_continuation_indent,
+        check_orphan_command,
+        check_empty_inline_command,
+        check_unescaped_hash,
+    ];
 
-/// Warning models a linter recommendation.
-#[derive(Debug, PartialEq)]
-pub struct Warning {
-    /// path denotes an offending file path.
-    pub path: String,
+    /// TEXT_CHECKS collects the set of available raw text makefile scans.
+    pub static ref TEXT_CHECKS: Vec<TextCheck> = NAMED_TEXT_CHECKS.iter().map(|e| e.1).collect();
+
+    /// OPT_IN_CHECKS collects the opt-in makefile scans
+    /// excluded from the default check set,
+    /// keyed by rule id for [config::Config] enable lists.
+    pub static ref OPT_IN_CHECKS: Vec<(&'static str, Check)> = vec![
+        ("MACRO_NAMING", check_macro_naming as Check),
+        ("UNSILENCED_ECHO", check_unsilenced_echo as Check),
+        ("GLOBAL_NOTPARALLEL", check_global_notparallel as Check),
+        ("SUFFIXES_CLEARED", check_suffixes_cleared as Check),
+        ("BOOLEAN_MACRO_DEFAULT", check_boolean_macro_default as Check),
+        ("UNUSED_PREREQUISITE", check_unused_prerequisite as Check),
+        ("PHONY_SPLIT", check_phony_split as Check),
+        ("PHONY_CONSOLIDATED", check_phony_consolidated as Check),
+        ("SIMILAR_TARGET", check_similar_target as Check),
+        ("OUT_OF_TREE_WRITE", check_out_of_tree_write as Check),
+        ("BACKTICK_SUBSTITUTION", check_backtick_substitution as Check),
+        ("FORCED_BUILD_MACRO", check_forced_build_macro as Check),
+    ];
 
-    /// line denotes the location of the relevant code section to enhance.
-    pub line: usize,
+    /// OPT_IN_TEXT_CHECKS collects the opt-in raw text makefile scans
+    /// excluded from the default check set,
+    /// keyed by rule id for [config::Config] enable lists.
+    pub static ref OPT_IN_TEXT_CHECKS: Vec<(&'static str, TextCheck)> = vec![
+        ("UNDOCUMENTED_TARGET", check_undocumented_target as TextCheck),
+        ("SPACE_BEFORE_COLON", check_space_before_colon as TextCheck),
+        ("TODO_COMMENT", check_todo_comment as TextCheck),
+        ("EXCESS_BLANK_LINE", check_excess_blank_line as TextCheck),
+    ];
 
-    /// message denotes a brief description of the recommendation.
-    pub message: String,
+    /// RULE_MESSAGES catalogs the short message for each check, by rule id.
+    pub static ref RULE_MESSAGES: Vec<&'static str> = vec![
+        UB_LATE_POSIX_MARKER,
+        UB_AMBIGUOUS_INCLUDE,
+        UB_MAKEFLAGS_ASSIGNMENT,
+        UB_SHELL_MACRO,
+        STRICT_POSIX,
+        POSIX_CONTRADICTION,
+        IMPLEMENTATTION_DEFINED_TARGET,
+        SHELL_METACHARACTER_TARGET,
+        PATTERN_RULE,
+        GNU_VPATH,
+        ORDER_ONLY_PREREQUISITE,
+        DOUBLE_COLON_RULE,
+        MAKEFILE_PRECEDENCE,
+        CURDIR_ASSIGNMENT_NOP,
+        READONLY_MACRO_ASSIGNMENT,
+        MAKECMDGOALS_EXPANSION,
+        PWD_EXPANSION,
+        PIPELINE_MASKING,
+        NONPORTABLE_ECHO_FLAG,
+        INTERACTIVE_COMMAND,
+        NONPORTABLE_FUNCTION,
+        WILDCARD_EXPANSION,
+        SHELL_ASSIGNMENT,
+        APPEND_UNDEFINED_MACRO,
+        RECURSIVE_MACRO,
+        UNUSED_MACRO,
+        UNDEFINED_MACRO,
+        WD_NOP,
+        MAKE_AFTER_CD,
+        WAIT_NOP,
+        PHONY_NOP,
+        DEFAULT_NOP,
+        PHONY_PATH,
+        REDUNDANT_NOTPARALLEL_WAIT,
+        LINEAR_WAIT,
+        REDUNDANT_SILENT_AT,
+        REDUNDANT_IGNORE_MINUS,
+        LAYERED_SILENCE,
+        GLOBAL_IGNORE,
+        SOFTEN_CLEAN,
+        SIMPLIFY_AT,
+        SIMPLIFY_MINUS,
+        IGNORED_SOLE_COMMAND,
+        INCONSISTENT_SILENCE,
+        COMMAND_COMMENT,
+        SHELL_COMMENT_ONLY_COMMAND,
+        PHONY_TARGET,
+        DANGLING_SPECIAL_PREREQUISITE,
+        NO_OP_RULE,
+        ABSOLUTE_INCLUDE,
+        LATE_INCLUDE,
+        DUPLICATE_PREREQUISITE,
+        SELF_DEPENDENCY,
+        SELF_MODIFICATION,
+        SUFFIXES_FRAGMENTATION,
+        WINDOWS_PATH_SEPARATOR,
+        UNTERMINATED_MACRO_EXPANSION,
+        REPEATED_COMMAND_PREFIX,
+        BLANK_COMMAND,
+        WHITESPACE_LEADING_COMMAND,
+        INCONSISTENT_CONTINUATION_INDENT,
+        COMMAND_LENGTH,
+        EXCESSIVE_CONTINUATION,
+        NON_ASCII_NAME,
+        EMPTY_MAKEFILE,
+        NO_RULES,
+        EXPORT_SPECIAL_TARGET,
+        ONESHELL,
+        RESERVED_TARGET,
+        RULE_ALL,
+        MISSING_FINAL_EOL,
+        TAB_FIELD_SEPARATOR,
+        ORPHAN_COMMAND,
+        EMPTY_INLINE_COMMAND,
+        UNESCAPED_HASH,
+        UNDOCUMENTED_TARGET,
+        MACRO_NAMING,
+        SPACE_BEFORE_COLON,
+        UNSILENCED_ECHO,
+        GLOBAL_NOTPARALLEL,
+        SUFFIXES_CLEARED,
+        BOOLEAN_MACRO_DEFAULT,
+        UNUSED_PREREQUISITE,
+        PHONY_SPLIT,
+        PHONY_CONSOLIDATED,
+        TODO_COMMENT,
+        SIMILAR_TARGET,
+        OUT_OF_TREE_WRITE,
+        EXCESS_BLANK_LINE,
+        BACKTICK_SUBSTITUTION,
+        FORCED_BUILD_MACRO,
+    ];
 }
 
-impl Warning {
-    /// new constructs a Warning.
-    pub fn new() -> Warning {
-        Warning {
-            path: WARNING_DEFAULT_PATH.to_string(),
-            line: 0,
-            message: String::new(),
-        }
-    }
+/// rule_id extracts the leading rule identifier from a warning message.
+pub fn rule_id(message: &str) -> &str {
+    message.split(':').next().unwrap_or(message)
 }
 
-impl Default for Warning {
-    /// default generates a basic Warning.
-    fn default() -> Self {
-        Warning::new()
-    }
+/// rule_url generates a link to the reference documentation for a rule id.
+pub fn rule_url(id: &str) -> String {
+    format!(
+        "https://github.com/mcandre/unmake/blob/master/WARNINGS.md#{}",
+        id.to_lowercase()
+    )
 }
 
-impl fmt::Display for Warning {
-    /// fmt renders a Warning for console use.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "warning: {}:", self.path)?;
+/// describe_rule queries the short message for a rule id.
+pub fn describe_rule(id: &str) -> Option<&'static str> {
+    RULE_MESSAGES.iter().find(|e| rule_id(e) == id).copied()
+}
 
-        if self.line > 0 {
-            write!(f, "{}:", self.line)?;
-        }
+#[test]
+fn test_rule_catalog() {
+    assert!(RULE_MESSAGES.len() >= CHECKS.len());
 
-        write!(f, " {}", self.message)
-    }
-}
+    assert_eq!(rule_id(PHONY_TARGET), "PHONY_TARGET");
 
-/// mock_md constructs simulated Metadata for a hypothetical path.
-///
-/// Assume a lintable POSIX makefile.
-///
-/// Certain fields are given dummy values.
-pub fn mock_md(pth: &str) -> inspect::Metadata {
-    inspect::Metadata {
-        path: pth.to_string(),
-        filename: pth.to_string(),
-        is_makefile: true,
-        build_system: String::new(),
-        is_machine_generated: false,
-        is_include_file: false,
-        is_empty: true,
-        lines: 0,
-        has_final_eol: false,
-    }
-}
+    assert!(rule_url("PHONY_TARGET").ends_with("#phony_target"));
 
-pub static UB_LATE_POSIX_MARKER: &str =
-    "UB_LATE_POSIX_MARKER: the special rule \".POSIX:\" should be the first uncommented instruction in POSIX makefiles, or else absent from *.include.mk files";
+    assert_eq!(describe_rule("PHONY_TARGET"), Some(PHONY_TARGET));
 
-/// check_ub_late_posix_marker reports UB_LATE_POSIX_MARKER violations.
-fn check_ub_late_posix_marker(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .enumerate()
-        .filter(|(i, e)| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs: _ } => {
-                (metadata.is_include_file || i > &0) && ts == &vec![".POSIX"]
-            }
-            _ => false,
-        })
-        .map(|(_, e)| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: UB_LATE_POSIX_MARKER.to_string(),
-        })
-        .collect()
+    assert_eq!(describe_rule("NO_SUCH_RULE"), None);
 }
 
-#[test]
-fn test_late_posix_marker() {
-    assert!(lint(&mock_md("-"), "PKG=curl\n.POSIX:\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+lazy_static::lazy_static! {
+    /// RULE_EXPLANATIONS catalogs long form guidance, keyed by rule id.
+    pub static ref RULE_EXPLANATIONS: std::collections::HashMap<&'static str, &'static str> = vec![
+        (
+            "UB_LATE_POSIX_MARKER",
+            r#"POSIX requires the ".POSIX:" special rule to appear before any other
+uncommented instruction. A late marker leaves makefile processing semantics
+unspecified, varying per make implementation.
 
-    assert!(!lint(&mock_md("-"), "# strict posix\n.POSIX:\nPKG=curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+Problem:
 
-    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG=curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+    PKG = curl
+    .POSIX:
 
-    let mut md_include = mock_md("provision.include.mk");
-    md_include.is_include_file = true;
+Corrected:
 
-    assert!(lint(&md_include, ".POSIX:\nPKG=curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+    .POSIX:
+    PKG = curl
 
-    assert!(!lint(&md_include, "PKG=curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+Include files like *.include.mk should omit the marker entirely, as their
+text pastes into outer makefiles below the first line."#,
+        ),
+        (
+            "UB_AMBIGUOUS_INCLUDE",
+            r#"Lines like "include =foo.mk" are ambiguous: they may parse as an include of
+the path "=foo.mk", or as a macro named "include". POSIX declines to specify
+a precedence.
 
-    assert!(lint(&mock_md("-"), ".POSIX:\ninclude =foo.mk\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
-}
+Problem:
 
-pub static UB_AMBIGUOUS_INCLUDE: &str =
-    "UB_AMBIGUOUS_INCLUDE: unclear whether include line or macro definition";
+    include =foo.mk
 
-/// check_ub_ambiguous_include reports UB_AMBIGUOUS_INCLUDE violations.
-fn check_ub_ambiguous_include(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::In { ps } => ps.iter().any(|e2| e2.starts_with('=')),
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: UB_AMBIGUOUS_INCLUDE.to_string(),
-        })
-        .collect()
-}
+Corrected:
 
-#[test]
-fn test_ub_ambiguous_include() {
-    assert!(lint(&mock_md("-"), ".POSIX:\ninclude = foo.mk\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
+    include foo.mk
 
-    assert!(lint(&mock_md("-"), ".POSIX:\ninclude =foo.mk\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
+Avoid equals signs in include paths, and avoid naming macros "include"."#,
+        ),
+        (
+            "UB_MAKEFLAGS_MACRO",
+            r#"MAKEFLAGS is reserved for make implementations to store command line flags,
+and forwards automatically to child $(MAKE) invocations. Assigning to it
+triggers unspecified behavior.
 
-    assert!(!lint(&mock_md("-"), ".POSIX:\ninclude=foo.mk\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
-}
+Problem:
 
-pub static UB_MAKEFLAGS_ASSIGNMENT: &str = "UB_MAKEFLAGS_MACRO: do not modify MAKEFLAGS macro";
+    MAKEFLAGS = -j
 
-/// check_ub_makeflags_assignment reports UB_MAKEFLAGS_ASSIGNMENT violations.
-fn check_ub_makeflags_assignment(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Mc { n, v: _ } => n == "MAKEFLAGS",
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: UB_MAKEFLAGS_ASSIGNMENT.to_string(),
-        })
-        .collect()
-}
+Corrected: remove the assignment, and pass flags on the command line
+instead, e.g. make -j."#,
+        ),
+        (
+            "UB_SHELL_MACRO",
+            r#"SHELL provides low level functionality to make internals. POSIX directs
+makefiles to neither read nor write the SHELL macro.
 
-#[test]
-fn test_ub_makeflags_assignment() {
-    assert!(
-        lint(&mock_md("-"), ".POSIX:\nMAKEFLAGS ?= -j\nMAKEFLAGS = -j\nMAKEFLAGS ::= -j\nMAKEFLAGS :::= -j\nMAKEFLAGS += -j\nMAKEFLAGS != echo \"-j\"\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&UB_MAKEFLAGS_ASSIGNMENT.to_string()));
+Problem:
 
-    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_MAKEFLAGS_ASSIGNMENT.to_string()));
-}
+    SHELL = sh
 
-pub static UB_SHELL_MACRO: &str = "UB_SHELL_MACRO: do not use or modify SHELL macro";
+    all:
+    <tab>$(SHELL) script.sh
 
-/// check_ub_shell_macro reports UB_SHELL_MACRO violations.
-fn check_ub_shell_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Mc { n, v: _ } => n == "SHELL",
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: UB_SHELL_MACRO.to_string(),
-        })
-        .collect()
-}
+Corrected:
 
-#[test]
-fn test_ub_shell_macro() {
-    assert!(lint(
-        &mock_md("-"),
-        ".POSIX:\nSHELL ?= sh\nSHELL = sh\nSHELL ::= sh\nSHELL :::= sh\nSHELL += sh\nSHELL != sh\n"
-    )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&UB_SHELL_MACRO.to_string()));
+    all:
+    <tab>./script.sh"#,
+        ),
+        (
+            "STRICT_POSIX",
+            r#"Leading a makefile with the ".POSIX:" marker instructs make implementations
+to apply standard POSIX semantics, improving portability and predictability.
 
-    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&UB_SHELL_MACRO.to_string()));
-}
+Problem:
 
-pub static MAKEFILE_PRECEDENCE: &str =
-    "MAKEFILE_PRECEDENCE: lowercase Makefile to makefile for launch speed";
+    PKG = curl
 
-/// check_makefile_precedence reports MAKEFILE_PRECEDENCE violations.
-fn check_makefile_precedence(metadata: &inspect::Metadata, _: &[ast::Gem]) -> Vec<Warning> {
-    if metadata.filename == "Makefile" {
-        return vec![Warning {
-            path: metadata.path.to_string(),
-            line: 0,
-            message: MAKEFILE_PRECEDENCE.to_string(),
-        }];
-    }
+Corrected:
 
-    Vec::new()
-}
+    .POSIX:
+    PKG = curl
 
-#[test]
-pub fn test_makefile_precedence() {
-    assert!(lint(&mock_md("Makefile"), ".POSIX:\nPKG=curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+Include files should instead be renamed like *.include.mk, which exempts
+them from this check."#,
+        ),
+        (
+            "POSIX_CONTRADICTION",
+            r#"The ".POSIX:" marker asserts strict POSIX compliance. Portability
+warnings in such a makefile contradict that explicit claim, so each
+receives an escalating POSIX_CONTRADICTION companion warning at the
+same location.
 
-    assert!(!lint(&mock_md("makefile"), ".POSIX:\nPKG=curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+Problem:
+
+    .POSIX:
+
+    all:
+    <tab>echo ${MAKECMDGOALS}
+
+Corrected: resolve the underlying portability warnings, or disable
+the POSIX_CONTRADICTION rule id via configuration or inline
+suppression comments to toggle the escalation off."#,
+        ),
+        (
+            "IMPLEMENTATTION_DEFINED_TARGET",
+            r#"POSIX make assigns no portable meaning to percent (%) or double-quote (")
+characters in targets or prerequisites. GNU pattern rules, for example,
+vendor lock a makefile onto gmake.
+
+Problem:
+
+    %.o: %.c
+    <tab>$(CC) -c $<
+
+Corrected:
+
+    .SUFFIXES: .c .o
+
+    .c.o:
+    <tab>$(CC) -c $<"#,
+        ),
+        (
+            "SHELL_METACHARACTER_TARGET",
+            r#"Glob and shell metacharacters in targets or prerequisites, such as
+asterisk (*), question mark (?), square brackets ([, ]), or dollar signs
+($) outside macro expansions, have no portable meaning in POSIX make and
+risk misinterpretation.
+
+Problem:
+
+    all: src/*.c
+    <tab>gcc -o foo src/*.c
+
+Corrected:
+
+    all: src/foo.c
+    <tab>gcc -o foo src/foo.c"#,
+        ),
+        (
+            "PATTERN_RULE",
+            r#"GNU pattern rules pairing percent (%) stems across targets and
+prerequisites vendor lock a makefile onto gmake. POSIX suffix rules
+express the same file extension translations portably.
+
+Problem:
+
+    %.o: %.c
+    <tab>$(CC) -c $<
+
+Corrected:
+
+    .SUFFIXES: .c .o
+
+    .c.o:
+    <tab>$(CC) -c $<"#,
+        ),
+        (
+            "GNU_VPATH",
+            r#"vpath directives and the VPATH variable configure GNU search paths
+for prerequisites. POSIX make implementations reject the directive,
+or else search only the working directory.
+
+Problem:
+
+    vpath %.c src
+
+    foo: foo.c
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    foo: src/foo.c
+    <tab>gcc -o foo src/foo.c"#,
+        ),
+        (
+            "ORDER_ONLY_PREREQUISITE",
+            r#"Order-only prerequisites following a pipe (|) are a GNU extension.
+POSIX make implementations reject the syntax, or else misread the pipe
+as an ordinary prerequisite name.
+
+Problem:
+
+    all: build | deps
+
+Corrected:
+
+    all: build deps"#,
+        ),
+        (
+            "DOUBLE_COLON_RULE",
+            r#"Double-colon rules are a GNU/BSD extension for declaring multiple
+independent rules per target. POSIX make defines no semantic for the
+double-colon separator.
+
+Problem:
+
+    all:: foo.c
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    all: foo.c
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "MAKEFILE_PRECEDENCE",
+            r#"POSIX make searches for ./makefile before ./Makefile. The lowercase name
+resolves marginally faster and signals POSIX intent.
+
+Problem: a file named "Makefile".
+
+Corrected: rename the file to "makefile"."#,
+        ),
+        (
+            "CURDIR_ASSIGNMENT_NOP",
+            r#"Assigning the CURDIR macro does not change the make process working
+directory; the assignment quietly fails to accomplish its apparent goal.
+
+Problem:
+
+    CURDIR = build
+
+Corrected: remove the assignment. Prefer command level directory options
+like tar -C <dir>, or promote complex logic to a dedicated script."#,
+        ),
+        (
+            "READONLY_MACRO_ASSIGNMENT",
+            r#"Macros like MAKE, MAKECMDGOALS, MAKEFILE_LIST, MAKELEVEL,
+MAKE_VERSION, and .VARIABLES are reserved for make implementations to
+manage. Assignments to these names are ignored, or else trigger
+unspecified behavior. MAKEFLAGS, SHELL, and CURDIR receive dedicated
+UB_MAKEFLAGS_MACRO, UB_SHELL_MACRO, and CURDIR_ASSIGNMENT_NOP warnings.
+
+Problem:
+
+    MAKE = gmake
+
+Corrected: remove the assignment, and select a make implementation on the
+command line instead."#,
+        ),
+        (
+            "MAKECMDGOALS_EXPANSION",
+            r#"The MAKECMDGOALS macro listing the targets named on the make command
+line is a GNU extension. POSIX make implementations leave the expansion
+blank, quietly altering behavior.
+
+Problem:
+
+    GOALS = $(MAKECMDGOALS)
+
+Corrected: restructure the logic into distinct targets, so that each
+target no longer needs to query which goals were requested."#,
+        ),
+        (
+            "PWD_EXPANSION",
+            r#"PWD is an environment variable set by the invoking shell, which may be
+stale, unset, or point at a symlinked path. make tracks its own current
+directory.
+
+Problem:
+
+    ROOT = $(PWD)
+
+Corrected:
+
+    ROOT = $(CURDIR)
+
+Note that CURDIR is a GNU/BSD extension. Strictly portable makefiles
+can spell out relative paths instead."#,
+        ),
+        (
+            "PIPELINE_MASKING",
+            r#"A pipeline reports only the last command's exit status. POSIX sh has
+no pipefail option, so a failure upstream is silently discarded and
+make continues as though the command succeeded.
+
+Problem:
+
+    test:
+    <tab>./run-tests | tee test.log
+
+Corrected:
+
+    test:
+    <tab>./run-tests >test.log 2>&1; s=$$?; cat test.log; exit $$s
+
+This check is heuristic, scanning for unquoted pipe operators. Pipes
+hidden in subshells, backticks, or macro expansions are not detected."#,
+        ),
+        (
+            "NONPORTABLE_ECHO_FLAG",
+            r#"echo flags like -e and -n behave differently across /bin/sh
+implementations: some print the flag literally, others interpret it.
+printf behaves consistently.
+
+Problem:
+
+    all:
+    <tab>echo -n "Hello World!"
+
+Corrected:
+
+    all:
+    <tab>printf '%s' "Hello World!""#,
+        ),
+        (
+            "INTERACTIVE_COMMAND",
+            r#"Commands awaiting interactive input, such as bare read invocations,
+-i prompting file utilities, and package managers without an
+auto-confirm flag, hang non-interactive CI builds.
+
+Problem:
+
+    deps:
+    <tab>apt-get install curl
+
+Corrected:
+
+    deps:
+    <tab>apt-get install -y curl
+
+This heuristic scans for commands in [DEFAULT_INTERACTIVE_COMMANDS].
+Customize the list with check_interactive_command_with."#,
+        ),
+        (
+            "NONPORTABLE_FUNCTION",
+            r#"Macro functions like $(shell ...), $(wildcard ...), and the GNU
+introspection functions $(origin ...), $(flavor ...), and $(value ...)
+are implementation extensions. POSIX make expands them to nothing,
+quietly altering behavior.
+
+Problem:
+
+    SRC = $(wildcard *.c)
+
+Corrected: enumerate the values explicitly, or promote complex logic to
+a dedicated script."#,
+        ),
+        (
+            "WILDCARD_EXPANSION",
+            r#"Filesystem-dependent functions like $(wildcard ...) and $(realpath ...)
+resolve against directory contents at parse time, so stray files quietly
+alter the build. Explicit file lists keep builds reproducible.
+
+Problem:
+
+    SRC = $(wildcard *.c)
+
+Corrected:
+
+    SRC = foo.c bar.c"#,
+        ),
+        (
+            "SHELL_ASSIGNMENT",
+            r#"The != operator runs a shell command while the makefile is parsed, even
+for build targets that never use the result. The output can vary across
+environments, undermining reproducible builds.
+
+Problem:
+
+    TIMESTAMP != date
+
+Corrected: precompute values, pass them on the command line, or scope the
+command to the rules that need it."#,
+        ),
+        (
+            "APPEND_UNDEFINED_MACRO",
+            r#"Appending to a macro that was never assigned behaves inconsistently:
+some make implementations treat the += as a plain =, others complain.
+Well known preset macros like CFLAGS and LDFLAGS are exempt.
+
+Problem:
+
+    FLAGS += -g
+
+Corrected:
+
+    FLAGS = -O2
+    FLAGS += -g"#,
+        ),
+        (
+            "RECURSIVE_MACRO",
+            r#"A = assignment whose value expands the macro's own name loops
+forever, and make implementations abort with an infinite recursion
+error at expansion time. += appends honor the prior value, so only
+plain = assignments are flagged.
+
+Problem:
+
+    FLAGS = $(FLAGS) -g
+
+Corrected:
+
+    FLAGS = -O2
+    FLAGS += -g"#,
+        ),
+        (
+            "UNUSED_MACRO",
+            r#"Macros defined but never expanded tend to indicate typos,
+or leftovers from refactoring. Conventional externally consumed macros
+like PREFIX and DESTDIR, which users override on the make command line,
+are exempt, as are include files, whose macros may be consumed elsewhere.
+
+Problem:
+
+    FLAGS = -g
+    all: all.c
+    <tab>cc -o all all.c
+
+Corrected:
+
+    FLAGS = -g
+    all: all.c
+    <tab>cc $(FLAGS) -o all all.c
+
+Alternatively, remove the unused macro definition."#,
+        ),
+        (
+            "UNDEFINED_MACRO",
+            r#"Expanding a macro that is never defined silently yields an empty string,
+masking typos in macro names. Well known preset macros like CC and MAKE
+are exempt, as are makefiles with include lines and include files,
+where further definitions may live elsewhere.
+
+Problem:
+
+    FLAGS = -g
+    all: all.c
+    <tab>cc $(FLAG) -o all all.c
+
+Corrected:
+
+    FLAGS = -g
+    all: all.c
+    <tab>cc $(FLAGS) -o all all.c"#,
+        ),
+        (
+            "WD_NOP",
+            r#"make resets the working directory between successive commands and rules, so
+a standalone cd, pushd, or popd does not persist into later commands. pushd
+and popd are also bash extensions, unavailable in strict POSIX sh.
+
+Problem:
+
+    all:
+    <tab>cd foo
+    <tab>./build.sh
+
+Corrected:
+
+    all:
+    <tab>cd foo && ./build.sh"#,
+        ),
+        (
+            "MAKE_AFTER_CD",
+            r#"Changing directory before launching a nested make works, but the
+portable $(MAKE) -C <dir> idiom expresses the same recursion directly,
+without shell chaining.
+
+Problem:
+
+    all:
+    <tab>cd foo && $(MAKE) install
+
+Corrected:
+
+    all:
+    <tab>$(MAKE) -C foo install"#,
+        ),
+        (
+            "WAIT_NOP",
+            r#".WAIT is a pseudo-prerequisite marker for ordering prerequisite processing.
+As a target, it has no effect.
+
+Problem:
+
+    .WAIT:
+
+Corrected:
+
+    test: test-1 .WAIT test-2"#,
+        ),
+        (
+            "PHONY_NOP",
+            r#"A .PHONY special rule with no prerequisites is ignored by make.
+
+Problem:
+
+    .PHONY:
+
+Corrected:
+
+    .PHONY: clean
+
+    clean:
+    <tab>-rm -rf bin"#,
+        ),
+        (
+            "DEFAULT_NOP",
+            r#"A .DEFAULT special rule supplies commands for targets lacking an
+explicit rule. A .DEFAULT declaration without commands does nothing.
+
+Problem:
+
+    .DEFAULT:;
+
+Corrected:
+
+    .DEFAULT:
+    <tab>echo "no rule for target""#,
+        ),
+        (
+            "PHONY_PATH",
+            r#"Phony targets are logical names, deliberately detached from the file
+system. Declaring a path like build/app as .PHONY disables the file-based
+caching that such artifact rules rely on.
+
+Problem:
+
+    .PHONY: build/app
+
+Corrected:
+
+    .PHONY: all
+
+    all: build/app"#,
+        ),
+        (
+            "REDUNDANT_NOTPARALLEL_WAIT",
+            r#".NOTPARALLEL: disables parallel processing globally, so per-rule .WAIT
+markers accomplish nothing further.
+
+Problem:
+
+    .NOTPARALLEL:
+
+    test: test-1 .WAIT test-2
+
+Corrected: drop either the .NOTPARALLEL declaration or the .WAIT markers."#,
+        ),
+        (
+            "LINEAR_WAIT",
+            r#".WAIT only matters when the separated prerequisites could otherwise
+process in parallel. When the right hand prerequisite already depends
+on the left hand one, directly or through intermediates, the
+dependency chain orders them and the marker is clutter.
+
+Problem:
+
+    all: a .WAIT b
+    b: a
+
+Corrected:
+
+    all: a b
+    b: a"#,
+        ),
+        (
+            "REDUNDANT_SILENT_AT",
+            r#"When .SILENT already covers a target, at (@) prefixes on its commands are
+superfluous.
+
+Problem:
+
+    .SILENT: lint
+
+    lint:
+    <tab>@unmake .
+
+Corrected:
+
+    .SILENT: lint
+
+    lint:
+    <tab>unmake ."#,
+        ),
+        (
+            "REDUNDANT_IGNORE_MINUS",
+            r#"When .IGNORE already covers a target, hyphen-minus (-) prefixes on its
+commands are superfluous.
+
+Problem:
+
+    .IGNORE: clean
+
+    clean:
+    <tab>-rm -rf bin
+
+Corrected:
+
+    .IGNORE: clean
+
+    clean:
+    <tab>rm -rf bin"#,
+        ),
+        (
+            "LAYERED_SILENCE",
+            r#"Declaring both a global .SILENT (or .IGNORE) and a per-target
+declaration for the same target over-specifies the behavior: the
+global declaration already covers every rule. Layering command
+prefixes on top receives separate REDUNDANT_SILENT_AT and
+REDUNDANT_IGNORE_MINUS warnings.
+
+Problem:
+
+    .SILENT:
+    .SILENT: lint
+
+    lint:
+    <tab>unmake .
+
+Corrected:
+
+    .SILENT:
+
+    lint:
+    <tab>unmake ."#,
+        ),
+        (
+            "GLOBAL_IGNORE",
+            r#".IGNORE: with no prerequisites disables exit code handling for every rule,
+inviting silent file corruption.
+
+Problem:
+
+    .IGNORE:
+
+Corrected:
+
+    .IGNORE: clean
+
+Alternatively, prefix individual commands with hyphen-minus (-)."#,
+        ),
+        (
+            "SOFTEN_CLEAN",
+            r#"Removal commands in clean rules halt on missing files unless softened
+with a hyphen-minus (-) prefix or an .IGNORE declaration, interrupting
+cleanup midway.
+
+Problem:
+
+    clean:
+    <tab>rm -rf bin
+
+Corrected:
+
+    clean:
+    <tab>-rm -rf bin"#,
+        ),
+        (
+            "SIMPLIFY_AT",
+            r#"Rules where every command carries an at (@) prefix read more clearly with a
+single .SILENT declaration.
+
+Problem:
+
+    welcome:
+    <tab>@echo foo
+    <tab>@echo bar
+
+Corrected:
+
+    .SILENT: welcome
+
+    welcome:
+    <tab>echo foo
+    <tab>echo bar"#,
+        ),
+        (
+            "SIMPLIFY_MINUS",
+            r#"Rules where every command carries a hyphen-minus (-) prefix read more
+clearly with a single .IGNORE declaration.
+
+Problem:
+
+    welcome:
+    <tab>-echo foo
+    <tab>-echo bar
+
+Corrected:
+
+    .IGNORE: welcome
+
+    welcome:
+    <tab>echo foo
+    <tab>echo bar"#,
+        ),
+        (
+            "IGNORED_SOLE_COMMAND",
+            r#"When a rule's only command carries a hyphen-minus (-) ignore prefix,
+every failure of the rule passes silently, and downstream rules
+proceed against missing or stale artifacts. Targets named clean*
+are exempt, as ignoring removal failures there is idiomatic.
+
+Problem:
+
+    install:
+    <tab>-cp all /usr/local/bin
+
+Corrected:
+
+    install:
+    <tab>cp all /usr/local/bin"#,
+        ),
+        (
+            "INCONSISTENT_SILENCE",
+            r#"A rule silencing most, but not all, of its commands with at (@) prefixes
+usually indicates an oversight.
+
+Problem:
+
+    welcome:
+    <tab>@echo foo
+    <tab>echo bar
+
+Corrected:
+
+    welcome:
+    <tab>@echo foo
+    <tab>@echo bar
+
+Alternatively, declare the target in a .SILENT rule and drop the prefixes."#,
+        ),
+        (
+            "COMMAND_COMMENT",
+            r#"Sharp (#) signs inside rule commands forward to the shell rather than acting
+as make comments, producing log noise and occasional failures.
+
+Problem:
+
+    foo: foo.c
+    <tab>#build foo
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    #build foo
+    foo: foo.c
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "SHELL_COMMENT_ONLY_COMMAND",
+            r#"A command consisting entirely of a sharp (#) comment still spawns a shell,
+only for the shell to discard the line. The note belongs at the make level.
+
+Problem:
+
+    foo: foo.c
+    <tab>#build foo
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    #build foo
+    foo: foo.c
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "PHONY_TARGET",
+            r#"make assumes rule targets are real file paths. Conventional artifactless
+targets such as all, lint, install, uninstall, publish, test*, and clean*
+should be declared .PHONY, so that file-based caching never skips them.
+
+Problem:
+
+    clean:
+    <tab>-rm -rf bin
+
+Corrected:
+
+    .PHONY: clean
+
+    clean:
+    <tab>-rm -rf bin
+
+A stray file literally named "clean" would otherwise halt the uncorrected
+rule forever."#,
+        ),
+        (
+            "DANGLING_SPECIAL_PREREQUISITE",
+            r#".SILENT, .IGNORE, and .PRECIOUS apply attributes to the targets named in
+their prerequisites. A prerequisite matching no declared target silently
+does nothing, usually indicating a misspelled or removed target name.
+
+Problem:
+
+    .SILENT: build test
+
+    build:
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    .SILENT: build
+
+    build:
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "NO_OP_RULE",
+            r#"A rule with no prerequisites and no commands gives make nothing to do,
+usually indicating a half-written rule or a misspelled phony declaration.
+
+Problem:
+
+    foo:;
+
+Corrected:
+
+    foo: foo.c
+    <tab>gcc -o foo foo.c
+
+Intentional placeholder targets should be declared .PHONY."#,
+        ),
+        (
+            "ABSOLUTE_INCLUDE",
+            r#"Include paths anchored to a filesystem root vary across machines,
+hurting portability and reproducibility. Prefer paths relative to the
+makefile's project.
+
+Problem:
+
+    include /etc/make/common.mk
+
+Corrected:
+
+    include sys/common.mk"#,
+        ),
+        (
+            "LATE_INCLUDE",
+            r#"Includes appearing after rules can shadow or reorder definitions
+unexpectedly. Grouping include lines near the top keeps the dependency
+surface visible at a glance.
+
+Problem:
+
+    all: foo.mk
+    <tab>echo "Hello World!"
+
+    include foo.mk
+
+Corrected:
+
+    include foo.mk
+
+    all: foo.mk
+    <tab>echo "Hello World!""#,
+        ),
+        (
+            "REPEATED_COMMAND_PREFIX",
+            r#"Repeating the same @, +, or - command prefix is superfluous.
+
+Problem:
+
+    test:
+    <tab>@@echo "Hello World!"
+
+Corrected:
+
+    test:
+    <tab>@echo "Hello World!""#,
+        ),
+        (
+            "BLANK_COMMAND",
+            r#"Commands reduced to prefixes and whitespace send an empty string to the
+shell, with indeterminate results.
+
+Problem:
+
+    test:
+    <tab>@
+
+Corrected: give the command something useful to do, or remove it."#,
+        ),
+        (
+            "WHITESPACE_LEADING_COMMAND",
+            r#"Whitespace at the start of a command, after any @/+/- prefixes, often
+betrays a typo in multiline instructions.
+
+Problem:
+
+    foo:
+    <tab> gcc -o foo foo.c
+
+Corrected:
+
+    foo:
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "INCONSISTENT_CONTINUATION_INDENT",
+            r#"Continuation lines within a single command can each begin with a tab, a
+space, or no indentation. Mixing these styles in one command confuses
+readers and some parsers.
+
+Problem:
+
+    foo:
+    <tab>gcc \
+    <tab><tab>-o \
+      foo foo.c
+
+Corrected:
+
+    foo:
+    <tab>gcc \
+    <tab><tab>-o \
+    <tab><tab>foo foo.c"#,
+        ),
+        (
+            "COMMAND_LENGTH",
+            r#"Operating systems cap the combined length of a command and its
+environment, commonly near ARG_MAX. A single command listing hundreds
+of files can exceed the cap on some systems, failing with confusing
+E2BIG errors.
+
+Problem:
+
+    huge:
+    <tab>gcc -o huge a.c b.c c.c ... (thousands of characters)
+
+Corrected: generate a response file, expand arguments incrementally
+with xargs, or split the work across several rules."#,
+        ),
+        (
+            "EXCESSIVE_CONTINUATION",
+            r#"A single command continued over many backslash-newlines is hard
+to read, diff, and maintain, and invites subtle whitespace bugs.
+Commands with 10 or more continuations warn by default.
+
+Problem:
+
+    all:
+    <tab>gcc \
+    <tab><tab>-DX=1 \
+    <tab><tab>-DX=2 \
+    <tab><tab>... (many more continuation lines)
+
+Corrected: extract the logic to a standalone script, or split the
+work across several commands or macros. Tune the threshold with
+check_excessive_continuation_with."#,
+        ),
+        (
+            "NON_ASCII_NAME",
+            r#"Some make implementations mishandle non-ASCII bytes in target,
+prerequisite, and macro names. Comments, macro values, and commands
+are left free to contain UTF-8.
+
+Problem:
+
+    buïld:
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    build:
+    <tab>gcc -o foo foo.c
+
+Extend the scan to macro values and commands with
+check_non_ascii_name_with."#,
+        ),
+        (
+            "SUFFIXES_FRAGMENTATION",
+            r#"Clearing the suffix list with ".SUFFIXES:" and then re-adding entries in
+separate rules is valid, but error-prone during refactors.
+
+Problem:
+
+    .SUFFIXES:
+    .SUFFIXES: .c .o
+
+Corrected:
+
+    .SUFFIXES: .c .o"#,
+        ),
+        (
+            "DUPLICATE_PREREQUISITE",
+            r#"Listing the same prerequisite multiple times in one rule is harmless to
+make, but usually indicates a copy/paste mistake.
+
+Problem:
+
+    foo: a.c a.c b.c
+    <tab>gcc -o foo a.c b.c
+
+Corrected:
+
+    foo: a.c b.c
+    <tab>gcc -o foo a.c b.c"#,
+        ),
+        (
+            "SELF_DEPENDENCY",
+            r#"A rule whose target appears in its own prerequisite list forms a circular
+dependency. make implementations may warn, drop the cycle, or loop,
+depending on flavor.
+
+Problem:
+
+    foo: foo
+    <tab>touch foo
+
+Corrected:
+
+    foo: foo.c
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "SELF_MODIFICATION",
+            r#"A command that rewrites the makefile currently executing produces
+surprising, non-reproducible builds: the rules that ran are no longer
+the rules on disk.
+
+Problem:
+
+    upgrade:
+    <tab>sed -i -e "s/VERSION = .*/VERSION = 2/" makefile
+
+Corrected: generate a separate configuration file instead, e.g.
+version.mk, and include it."#,
+        ),
+        (
+            "EMPTY_MAKEFILE",
+            r#"An empty makefile declares no macros and no rules, giving make nothing to
+do. Empty makefiles are usually committed by mistake.
+
+Corrected: populate the makefile, or remove it from version control."#,
+        ),
+        (
+            "NO_RULES",
+            r#"A makefile without at least one non-special rule gives make nothing to do.
+
+Problem:
+
+    .POSIX:
+    PKG = curl
+
+Corrected: declare at least one rule, or rename the file like *.include.mk
+to mark it as an include fragment."#,
+        ),
+        (
+            "EXPORT_SPECIAL_TARGET",
+            r#"Special targets like GNU .EXPORT_ALL_VARIABLES and BSD .EXPORT dump
+make macros into the command environment. The behavior is non-portable
+and prone to leaking build configuration.
+
+Problem:
+
+    .EXPORT_ALL_VARIABLES:
+
+Corrected: remove the special target, and pass values explicitly to the
+commands that need them, e.g. PKG="$(PKG)" ./script.sh"#,
+        ),
+        (
+            "ONESHELL",
+            r#"GNU .ONESHELL runs all commands of a rule in a single shell
+invocation. POSIX make launches a fresh shell per command, discarding
+shell variables and other state between commands. Makefiles written
+assuming .ONESHELL silently misbehave elsewhere.
+
+Problem:
+
+    all:
+    <tab>PKG=curl
+    <tab>echo "$$PKG"
+
+Corrected:
+
+    all:
+    <tab>PKG=curl && echo "$$PKG""#,
+        ),
+        (
+            "RESERVED_TARGET",
+            r#"Targets named like .POSIX... or .(A-Z)... are reserved for future POSIX
+standardization or implementation extensions. Such names are either
+non-portable or typos of well-known special targets.
+
+Problem:
+
+    .TEST:
+    <tab>echo "Hello World!"
+
+Corrected:
+
+    test:
+    <tab>echo "Hello World!""#,
+        ),
+        (
+            "RULE_ALL",
+            r#"make treats the first non-special rule as the default rule, and convention
+names that rule "all". Projects preferring another name, such as the
+self-documenting "help" convention, can set default_rule_name in a
+--config file.
+
+Problem:
+
+    build:
+    <tab>echo "Hello World!"
+
+Corrected:
+
+    all: build
+
+    build:
+    <tab>echo "Hello World!""#,
+        ),
+        (
+            "TAB_FIELD_SEPARATOR",
+            r#"Tabs between targets or prerequisites parse, but render inconsistently
+across editors, and invite confusion with the tab indentation that
+distinguishes rule commands.
+
+Problem:
+
+    foo: a.c<tab>b.c
+
+Corrected:
+
+    foo: a.c b.c"#,
+        ),
+        (
+            "ORPHAN_COMMAND",
+            r#"make rejects tab indented commands that do not follow a rule
+declaration, reporting terse errors like "commands commence before
+first target". Orphan commands usually indicate a deleted rule line,
+or accidental tab indentation.
+
+Problem:
+
+    PKG = curl
+    <tab>echo "$(PKG)"
+
+Corrected:
+
+    PKG = curl
+    all:
+    <tab>echo "$(PKG)""#,
+        ),
+        (
+            "EMPTY_INLINE_COMMAND",
+            r#"A trailing semicolon on a rule declaring prerequisites produces an
+empty inline command, usually a leftover from a deleted command.
+Prerequisite-free no-op declarations like "foo:;" are skipped, as the
+semicolon there deliberately stands in for a command.
+
+Problem:
+
+    all: build;
+
+Corrected:
+
+    all: build"#,
+        ),
+        (
+            "UNESCAPED_HASH",
+            r#"A hash begins a comment anywhere outside a command, including
+mid-value in a macro definition, silently truncating the remainder.
+
+Problem:
+
+    CFLAGS = -DX=a#b
+
+Corrected:
+
+    CFLAGS = -DX=a\#b
+
+Trailing annotations are better moved to a comment line above the
+definition."#,
+        ),
+        (
+            "WINDOWS_PATH_SEPARATOR",
+            r#"Backslash path separators like dir\foo.c do not resolve as paths in POSIX
+make, and risk misinterpretation as escape sequences.
+
+Problem:
+
+    foo: dir\foo.c
+    <tab>gcc -o foo dir/foo.c
+
+Corrected:
+
+    foo: dir/foo.c
+    <tab>gcc -o foo dir/foo.c"#,
+        ),
+        (
+            "UNTERMINATED_MACRO_EXPANSION",
+            r#"A macro expansion opener "$(" or "${" lacking a matching closing
+delimiter silently expands to something other than the intended macro
+value.
+
+Escape literal dollar signs as "$$".
+
+Problem:
+
+    PKG = $(CURL
+
+Corrected:
+
+    PKG = $(CURL)"#,
+        ),
+        (
+            "UNDOCUMENTED_TARGET",
+            r#"Projects following the self-documenting makefile convention derive help
+output from comments above each rule. This opt-in check warns when a
+non-special rule lacks a documentation comment on the preceding line.
+
+Problem:
+
+    all:
+    <tab>echo "Hello World!"
+
+Corrected:
+
+    # greet the world
+    all:
+    <tab>echo "Hello World!""#,
+        ),
+        (
+            "MACRO_NAMING",
+            r#"Makefile macros are conventionally named UPPER_SNAKE_CASE; lowercase
+macro names are easily confused with targets. This opinionated, opt-in
+check warns when a user-defined macro name contains lowercase letters.
+
+Problem:
+
+    pkg = curl
+
+Corrected:
+
+    PKG = curl"#,
+        ),
+        (
+            "SPACE_BEFORE_COLON",
+            r#"Whitespace between a target and its colon parses in most make
+implementations, but older BSD makes treated the form inconsistently.
+This pedantic, opt-in check warns on the extra whitespace.
+
+Problem:
+
+    foo : foo.c
+    <tab>gcc -o foo foo.c
+
+Corrected:
+
+    foo: foo.c
+    <tab>gcc -o foo foo.c"#,
+        ),
+        (
+            "UNSILENCED_ECHO",
+            r#"make echoes each command before running it, so an unsilenced echo or
+printf status command prints twice, cluttering build output. This
+opinionated, opt-in check suggests an at (@) prefix or a .SILENT
+declaration for such commands.
+
+Commands redirecting output to a file are skipped.
+
+Problem:
+
+    all:
+    <tab>echo "building..."
+
+Corrected:
+
+    all:
+    <tab>@echo "building...""#,
+        ),
+        (
+            "GLOBAL_NOTPARALLEL",
+            r#".NOTPARALLEL disables parallel processing for the whole makefile,
+often as an over-broad workaround for a handful of racy rules. This
+advisory, opt-in check suggests scoping ordering constraints instead.
+
+Problem:
+
+    .NOTPARALLEL:
+
+Corrected:
+
+    all: build .WAIT test
+
+Alternatively, declare the true prerequisites of each racy rule."#,
+        ),
+        (
+            "SUFFIXES_CLEARED",
+            r#"A bare ".SUFFIXES:" declaration clears the suffix list, disabling all
+built-in inference rules. That is sometimes intended, but it also stops
+suffix rules declared later from ever triggering. This advisory, opt-in
+check asks the author to confirm intent.
+
+Problem:
+
+    .SUFFIXES:
+
+    .c.o:
+    <tab>$(CC) -c $<
+
+Corrected:
+
+    .SUFFIXES: .c .o
+
+    .c.o:
+    <tab>$(CC) -c $<"#,
+        ),
+        (
+            "BOOLEAN_MACRO_DEFAULT",
+            r#"make has no boolean type: any non-empty macro value reads as "set,"
+so a default like DEBUG ?= false still enables checks of the form
+"test -n". This advisory, opt-in check suggests empty vs. non-empty
+conventions instead.
+
+Problem:
+
+    DEBUG ?= false
+
+Corrected:
+
+    DEBUG ?=
+
+Enable the behavior by overriding the macro with any non-empty value,
+e.g. make DEBUG=1."#,
+        ),
+        (
+            "UNUSED_PREREQUISITE",
+            r#"When a rule lists prerequisites that its commands never reference,
+directly or through automatic macros like $<, $?, or $*, the
+dependency may be decorative or stale. This advisory, opt-in
+heuristic is prone to false positives, e.g. for rules delegating
+work to scripts.
+
+Problem:
+
+    foo: a.c b.c
+    <tab>gcc -o foo main.c
+
+Corrected:
+
+    foo: main.c
+    <tab>gcc -o foo main.c"#,
+        ),
+        (
+            "PHONY_SPLIT",
+            r#"Some style guides prefer a single consolidated .PHONY line. This
+advisory, opt-in check enforces that policy; enable either this check
+or PHONY_CONSOLIDATED, not both.
+
+Problem:
+
+    .PHONY: build
+    build:;
+    .PHONY: test
+    test:;
+
+Corrected:
+
+    .PHONY: build test
+    build:;
+    test:;"#,
+        ),
+        (
+            "PHONY_CONSOLIDATED",
+            r#"Some style guides prefer per-target .PHONY lines adjacent to each
+rule. This advisory, opt-in check enforces that policy; enable either
+this check or PHONY_SPLIT, not both.
+
+Problem:
+
+    .PHONY: build test
+    build:;
+    test:;
+
+Corrected:
+
+    .PHONY: build
+    build:;
+    .PHONY: test
+    test:;"#,
+        ),
+        (
+            "TODO_COMMENT",
+            r#"Comments containing TODO, FIXME, or XXX markers track known debt.
+This informational, opt-in check surfaces them for teams that want
+debt visible in lint reports.
+
+Problem:
+
+    # TODO: cache artifacts
+
+Corrected: resolve the debt, or track it in an issue tracker."#,
+        ),
+        (
+            "SIMILAR_TARGET",
+            r#"When two target names sit within edit distance 1 of each other,
+one is often a fat-fingered typo, secretly turning a prerequisite
+phony. This fuzzy, opt-in heuristic can false-positive on intentional
+target families like test-1 and test-2.
+
+Problem:
+
+    build:
+    <tab>cargo build
+
+    buidl: build
+
+Corrected:
+
+    build:
+    <tab>cargo build
+
+Tune the edit distance with check_similar_target_with."#,
+        ),
+        (
+            "OUT_OF_TREE_WRITE",
+            r#"Commands redirecting output or copying files to absolute paths, or to
+paths escaping the project with "..", hurt reproducibility and
+hermeticity. This advisory, opt-in heuristic skips destinations
+expanding macros, covering typical $(DESTDIR) and $(PREFIX) install
+patterns, along with /dev/ device paths.
+
+Problem:
+
+    install: foo
+    <tab>cp foo /usr/local/bin/foo
+
+Corrected:
+
+    install: foo
+    <tab>install -m 755 foo $(DESTDIR)$(PREFIX)/bin/foo"#,
+        ),
+        (
+            "EXCESS_BLANK_LINE",
+            r#"Leading blank lines, trailing blank lines beyond the final newline,
+and runs of three or more consecutive blank lines add visual noise.
+This stylistic, opt-in check flags each offending run.
+
+Problem:
+
+    all:
+    <tab>echo "Hello World!"
+    <blank>
+    <blank>
+    <blank>
+    test:
+    <tab>echo "Hi World!"
+
+Corrected: collapse each run to at most one or two blank lines."#,
+        ),
+        (
+            "BACKTICK_SUBSTITUTION",
+            r#"Backtick command substitution is legal sh, but error-prone and hard
+to nest. $(...) shell substitution nests cleanly. Note that shell
+dollar signs in make commands escape as double dollar signs, lest
+make expand them as make macros. Backticks inside single quotes are
+literal and skipped.
+
+Problem:
+
+    all:
+    <tab>echo `git rev-parse HEAD`
+
+Corrected:
+
+    all:
+    <tab>echo $$(git rev-parse HEAD)"#,
+        ),
+        (
+            "FORCED_BUILD_MACRO",
+            r#"Packagers and integrators customarily override build tool macros
+like CC and CFLAGS, via command line arguments or the environment.
+Assigning such macros with = forces the makefile author's choice,
+whereas ?= supplies a default that still honors overrides. Only
+assignments referenced in rule commands are flagged.
+
+Problem:
+
+    CC = gcc
+
+    all:
+    <tab>$(CC) -o all all.c
+
+Corrected:
+
+    CC ?= gcc
+
+    all:
+    <tab>$(CC) -o all all.c"#,
+        ),
+        (
+            "MISSING_FINAL_EOL",
+            r#"UNIX text files terminate every line, including the last, with a Line Feed.
+Files missing the final LF can process poorly in text tooling.
+
+Problem: PKG = curl<EOF>
+
+Corrected: PKG = curl<LF><EOF>
+
+Configure EditorConfig and text editors to apply a final EOL automatically."#,
+        ),
+    ]
+    .into_iter()
+    .collect::<std::collections::HashMap<&'static str, &'static str>>();
+}
+
+/// explain_rule queries the long form guidance for a rule id.
+pub fn explain_rule(id: &str) -> Option<&'static str> {
+    RULE_EXPLANATIONS.get(id).copied()
+}
+
+#[test]
+fn test_rule_explanations() {
+    for message in RULE_MESSAGES.iter() {
+        assert!(
+            explain_rule(rule_id(message)).is_some(),
+            "missing explanation for {}",
+            rule_id(message)
+        );
+    }
+
+    assert!(explain_rule("NO_SUCH_RULE").is_none());
+}
+
+/// Check implements a linter scan.
+pub type Check = fn(&inspect::Metadata, &[ast::Gem]) -> Vec<Warning>;
+
+/// TextCheck implements a linter scan over raw makefile text,
+/// for lints concerning details erased during parsing.
+pub type TextCheck = fn(&inspect::Metadata, &str) -> Vec<Warning>;
+
+/// Warning models a linter recommendation.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct Warning {
+    /// path denotes an offending file path.
+    pub path: String,
+
+    /// line denotes the location of the relevant code section to enhance.
+    pub line: usize,
+
+    /// offset denotes the byte offset
+    /// of the relevant code section from the start of the file,
+    /// or zero when unavailable.
+    pub offset: usize,
+
+    /// message denotes a brief description of the recommendation.
+    pub message: String,
+}
+
+impl Warning {
+    /// new constructs a Warning.
+    pub fn new() -> Warning {
+        Warning {
+            path: WARNING_DEFAULT_PATH.to_string(),
+            line: 0,
+            offset: 0,
+            message: String::new(),
+        }
+    }
+}
+
+impl Default for Warning {
+    /// default generates a basic Warning.
+    fn default() -> Self {
+        Warning::new()
+    }
+}
+
+impl fmt::Display for Warning {
+    /// fmt renders a Warning for console use.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "warning: {}:", self.path)?;
+
+        if self.line > 0 {
+            write!(f, "{}:", self.line)?;
+        }
+
+        write!(f, " {}", self.message)
+    }
+}
+
+/// OutputFormat enumerates the supported warning rendering formats.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Plain renders human readable console lines.
+    Plain,
+
+    /// Json renders JSON objects.
+    Json,
+
+    /// Sarif renders SARIF 2.1.0 documents.
+    Sarif,
+}
+
+impl str::FromStr for OutputFormat {
+    type Err = String;
+
+    /// from_str parses an OutputFormat from a command line style name.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            "sarif" => Ok(OutputFormat::Sarif),
+            _ => Err(format!("unknown format: {}", s)),
+        }
+    }
+}
+
+/// sarif_result renders a Warning as a SARIF result object.
+fn sarif_result(warning: &Warning) -> serde_json::Value {
+    let mut physical_location: serde_json::Value = serde_json::json!({
+        "artifactLocation": {
+            "uri": warning.path,
+        },
+    });
+
+    if warning.line > 0 {
+        physical_location["region"] = serde_json::json!({
+            "startLine": warning.line,
+            "byteOffset": warning.offset,
+        });
+    }
+
+    serde_json::json!({
+        "ruleId": rule_id(&warning.message),
+        "level": "warning",
+        "message": {
+            "text": warning.message,
+        },
+        "locations": [{
+            "physicalLocation": physical_location,
+        }],
+    })
+}
+
+/// json_warning renders a Warning as a JSON object,
+/// annotated with the documentation url for its rule.
+fn json_warning(warning: &Warning) -> serde_json::Value {
+    let mut value: serde_json::Value = serde_json::json!(warning);
+    value["url"] = serde_json::json!(rule_url(rule_id(&warning.message)));
+    value
+}
+
+/// sarif_rules renders reportingDescriptor metadata
+/// for each distinct rule among the given warnings,
+/// linking documentation urls.
+fn sarif_rules(warnings: &[Warning]) -> Vec<serde_json::Value> {
+    let mut ids: Vec<&str> = Vec::new();
+
+    for warning in warnings {
+        let id: &str = rule_id(&warning.message);
+
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    ids.into_iter()
+        .map(|id| {
+            serde_json::json!({
+                "id": id,
+                "helpUri": rule_url(id),
+            })
+        })
+        .collect()
+}
+
+/// format_warning renders a single Warning in the given output format,
+/// without a trailing newline.
+pub fn format_warning(warning: &Warning, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Plain => warning.to_string(),
+        OutputFormat::Json => json_warning(warning).to_string(),
+        OutputFormat::Sarif => sarif_result(warning).to_string(),
+    }
+}
+
+/// format_warnings renders a batch of Warnings in the given output format,
+/// as a complete document with a trailing newline.
+pub fn format_warnings(warnings: &[Warning], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Plain => warnings
+            .iter()
+            .map(|e| format!("{}\n", e))
+            .collect::<String>(),
+        OutputFormat::Json => {
+            let mut file_counts: HashMap<&String, usize> = HashMap::new();
+
+            for warning in warnings {
+                *file_counts.entry(&warning.path).or_insert(0) += 1;
+            }
+
+            format!(
+                "{}\n",
+                serde_json::json!({
+                    "warnings": warnings
+                        .iter()
+                        .map(json_warning)
+                        .collect::<Vec<serde_json::Value>>(),
+                    "total": warnings.len(),
+                    "files": file_counts,
+                })
+            )
+        }
+        OutputFormat::Sarif => format!(
+            "{}\n",
+            serde_json::json!({
+                "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+                "version": "2.1.0",
+                "runs": [{
+                    "tool": {
+                        "driver": {
+                            "name": env!("CARGO_PKG_NAME"),
+                            "version": env!("CARGO_PKG_VERSION"),
+                            "informationUri": "https://github.com/mcandre/unmake",
+                            "rules": sarif_rules(warnings),
+                        },
+                    },
+                    "results": warnings
+                        .iter()
+                        .map(sarif_result)
+                        .collect::<Vec<serde_json::Value>>(),
+                }],
+            })
+        ),
+    }
+}
+
+#[test]
+fn test_format_warning() {
+    let warning: Warning = Warning {
+        path: "makefile".to_string(),
+        line: 2,
+        offset: 9,
+        message: STRICT_POSIX.to_string(),
+    };
+
+    assert_eq!(
+        format_warning(&warning, OutputFormat::Plain),
+        format!("warning: makefile:2: {}", STRICT_POSIX)
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&format_warning(&warning, OutputFormat::Json)).unwrap();
+    assert_eq!(json["path"], "makefile");
+    assert_eq!(json["line"], 2);
+    assert_eq!(json["url"], rule_url("STRICT_POSIX"));
+
+    let sarif: serde_json::Value =
+        serde_json::from_str(&format_warning(&warning, OutputFormat::Sarif)).unwrap();
+    assert_eq!(sarif["ruleId"], "STRICT_POSIX");
+
+    let json_document: serde_json::Value = serde_json::from_str(&format_warnings(
+        &[Warning {
+            path: "makefile".to_string(),
+            line: 2,
+            offset: 9,
+            message: STRICT_POSIX.to_string(),
+        }],
+        OutputFormat::Json,
+    ))
+    .unwrap();
+    assert_eq!(
+        json_document["warnings"][0]["url"],
+        rule_url("STRICT_POSIX")
+    );
+
+    let document: serde_json::Value = serde_json::from_str(&format_warnings(
+        &[warning],
+        OutputFormat::Sarif,
+    ))
+    .unwrap();
+    assert_eq!(document["version"], "2.1.0");
+    assert_eq!(document["runs"][0]["results"][0]["ruleId"], "STRICT_POSIX");
+
+    let rules: &serde_json::Value = &document["runs"][0]["tool"]["driver"]["rules"];
+    assert_eq!(rules[0]["id"], "STRICT_POSIX");
+    assert_eq!(rules[0]["helpUri"], rule_url("STRICT_POSIX"));
+
+    assert_eq!(
+        "plain".parse::<OutputFormat>().unwrap(),
+        OutputFormat::Plain
+    );
+    assert!("yaml".parse::<OutputFormat>().is_err());
+}
+
+/// mock_md constructs simulated Metadata for a hypothetical path.
+///
+/// Assume a lintable POSIX makefile.
+///
+/// Certain fields are given dummy values.
+pub fn mock_md(pth: &str) -> inspect::Metadata {
+    inspect::Metadata {
+        path: pth.to_string(),
+        filename: pth.to_string(),
+        is_makefile: true,
+        build_system: String::new(),
+        is_machine_generated: false,
+        is_include_file: false,
+        is_empty: false,
+        lines: 0,
+        has_final_eol: false,
+    }
+}
+
+pub static UB_LATE_POSIX_MARKER: &str =
+    "UB_LATE_POSIX_MARKER: the special rule \".POSIX:\" should be the first uncommented instruction in POSIX makefiles, or else absent from *.include.mk files";
+
+/// check_ub_late_posix_marker reports UB_LATE_POSIX_MARKER violations.
+fn check_ub_late_posix_marker(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .enumerate()
+        .filter(|(i, e)| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => {
+                (metadata.is_include_file || i > &0) && ts == &vec![".POSIX"]
+            }
+            _ => false,
+        })
+        .map(|(_, e)| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: UB_LATE_POSIX_MARKER.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_late_posix_marker() {
+    assert!(lint(&mock_md("-"), "PKG=curl\n.POSIX:\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+
+    assert!(!lint(&mock_md("-"), "# strict posix\n.POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+
+    let mut md_include = mock_md("provision.include.mk");
+    md_include.is_include_file = true;
+
+    assert!(lint(&md_include, ".POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+
+    assert!(!lint(&md_include, "PKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_LATE_POSIX_MARKER.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\ninclude =foo.mk\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
+}
+
+pub static UB_AMBIGUOUS_INCLUDE: &str =
+    "UB_AMBIGUOUS_INCLUDE: unclear whether include line or macro definition";
+
+/// check_ub_ambiguous_include reports UB_AMBIGUOUS_INCLUDE violations.
+fn check_ub_ambiguous_include(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::In { ps } => ps.iter().any(|e2| e2.starts_with('=')),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: UB_AMBIGUOUS_INCLUDE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_ub_ambiguous_include() {
+    assert!(lint(&mock_md("-"), ".POSIX:\ninclude = foo.mk\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\ninclude =foo.mk\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\ninclude=foo.mk\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_AMBIGUOUS_INCLUDE.to_string()));
+}
+
+pub static UB_MAKEFLAGS_ASSIGNMENT: &str = "UB_MAKEFLAGS_MACRO: do not modify MAKEFLAGS macro";
+
+/// check_reserved_macro collects warnings for assignments
+/// to the named reserved macro, with the name's dedicated message.
+fn check_reserved_macro(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+    name: &str,
+    message: &str,
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n, op: _, v: _ } => n == name,
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: message.to_string(),
+        })
+        .collect()
+}
+
+/// check_ub_makeflags_assignment reports UB_MAKEFLAGS_ASSIGNMENT violations.
+fn check_ub_makeflags_assignment(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_reserved_macro(metadata, gems, "MAKEFLAGS", UB_MAKEFLAGS_ASSIGNMENT)
+}
+
+#[test]
+fn test_ub_makeflags_assignment() {
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nMAKEFLAGS ?= -j\nMAKEFLAGS = -j\nMAKEFLAGS ::= -j\nMAKEFLAGS :::= -j\nMAKEFLAGS += -j\nMAKEFLAGS != echo \"-j\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&UB_MAKEFLAGS_ASSIGNMENT.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_MAKEFLAGS_ASSIGNMENT.to_string()));
+}
+
+pub static UB_SHELL_MACRO: &str = "UB_SHELL_MACRO: do not use or modify SHELL macro";
+
+/// check_ub_shell_macro reports UB_SHELL_MACRO violations.
+fn check_ub_shell_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_reserved_macro(metadata, gems, "SHELL", UB_SHELL_MACRO)
+}
+
+#[test]
+fn test_ub_shell_macro() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nSHELL ?= sh\nSHELL = sh\nSHELL ::= sh\nSHELL :::= sh\nSHELL += sh\nSHELL != sh\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UB_SHELL_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UB_SHELL_MACRO.to_string()));
+}
+
+pub static MAKEFILE_PRECEDENCE: &str =
+    "MAKEFILE_PRECEDENCE: lowercase Makefile to makefile for launch speed";
+
+/// check_makefile_precedence reports MAKEFILE_PRECEDENCE violations.
+fn check_makefile_precedence(metadata: &inspect::Metadata, _: &[ast::Gem]) -> Vec<Warning> {
+    if metadata.filename == "Makefile" {
+        return vec![Warning {
+            path: metadata.path.to_string(),
+            line: 0,
+            offset: 0,
+            message: MAKEFILE_PRECEDENCE.to_string(),
+        }];
+    }
+
+    Vec::new()
+}
+
+#[test]
+pub fn test_makefile_precedence() {
+    assert!(lint(&mock_md("Makefile"), ".POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+
+    assert!(!lint(&mock_md("makefile"), ".POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKEFILE_PRECEDENCE.to_string()));
 
     assert!(!lint(&mock_md("foo.mk"), ".POSIX:\nPKG=curl\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+
+    assert!(!lint(&mock_md("foo.Makefile"), ".POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+
+    assert!(!lint(&mock_md("foo.makefile"), ".POSIX:\nPKG=curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+}
+
+pub static CURDIR_ASSIGNMENT_NOP: &str =
+    "CURDIR_ASSIGNMENT_NOP: CURDIR assignment does not change the make working directory";
+
+/// check_curdir_assignment_nop reports CURDIR_ASSIGNMENT_NOP violations.
+fn check_curdir_assignment_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_reserved_macro(metadata, gems, "CURDIR", CURDIR_ASSIGNMENT_NOP)
+}
+
+#[test]
+pub fn test_curdir_assignment_nop() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nCURDIR = build\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&CURDIR_ASSIGNMENT_NOP.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&CURDIR_ASSIGNMENT_NOP.to_string()));
+}
+
+lazy_static::lazy_static! {
+    /// READONLY_MACROS collects macro names reserved for make implementations,
+    /// beyond the MAKEFLAGS, SHELL, and CURDIR macros covered by dedicated checks.
+    pub static ref READONLY_MACROS: HashSet<&'static str> = vec![
+        "MAKE",
+        "MAKECMDGOALS",
+        "MAKEFILE_LIST",
+        "MAKELEVEL",
+        "MAKE_VERSION",
+        ".VARIABLES",
+    ]
+    .into_iter()
+    .collect::<HashSet<&'static str>>();
+}
+
+pub static READONLY_MACRO_ASSIGNMENT: &str =
+    "READONLY_MACRO_ASSIGNMENT: do not modify reserved macros such as MAKE, MAKECMDGOALS, or .VARIABLES";
+
+/// check_readonly_macro_assignment reports READONLY_MACRO_ASSIGNMENT violations.
+fn check_readonly_macro_assignment(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+) -> Vec<Warning> {
+    READONLY_MACROS
+        .iter()
+        .flat_map(|name| check_reserved_macro(metadata, gems, name, READONLY_MACRO_ASSIGNMENT))
+        .collect()
+}
+
+#[test]
+fn test_readonly_macro_assignment() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nMAKE = gmake\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&READONLY_MACRO_ASSIGNMENT.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nMAKECMDGOALS = all\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&READONLY_MACRO_ASSIGNMENT.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\n.VARIABLES = PKG\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&READONLY_MACRO_ASSIGNMENT.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nMAKEFILE_LIST = makefile\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&READONLY_MACRO_ASSIGNMENT.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nMAKELEVEL = 0\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&READONLY_MACRO_ASSIGNMENT.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&READONLY_MACRO_ASSIGNMENT.to_string()));
+}
+
+pub static MAKECMDGOALS_EXPANSION: &str =
+    "MAKECMDGOALS_EXPANSION: MAKECMDGOALS is a GNU extension";
+
+/// contains_makecmdgoals reports whether a string expands the MAKECMDGOALS macro.
+fn contains_makecmdgoals(s: &str) -> bool {
+    s.contains("$(MAKECMDGOALS)") || s.contains("${MAKECMDGOALS}")
+}
+
+/// check_makecmdgoals_expansion reports MAKECMDGOALS_EXPANSION violations.
+fn check_makecmdgoals_expansion(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n: _, op: _, v } => contains_makecmdgoals(v),
+            ast::Ore::Ru { dc: _, os: _, ps, ts: _, cs } => {
+                ps.iter().any(|e2| contains_makecmdgoals(e2))
+                    || cs.iter().any(|e2| contains_makecmdgoals(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: MAKECMDGOALS_EXPANSION.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_makecmdgoals_expansion() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nGOALS = $(MAKECMDGOALS)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKECMDGOALS_EXPANSION.to_string()));
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nall: $(MAKECMDGOALS)\n\techo done\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&MAKECMDGOALS_EXPANSION.to_string())
+    );
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:;echo ${MAKECMDGOALS}\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKECMDGOALS_EXPANSION.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKECMDGOALS_EXPANSION.to_string()));
+}
+
+pub static PWD_EXPANSION: &str =
+    "PWD_EXPANSION: PWD is an environment value that may be stale; prefer $(CURDIR) for make's working directory";
+
+/// contains_pwd reports whether a string expands the PWD environment variable.
+fn contains_pwd(s: &str) -> bool {
+    s.contains("$(PWD)") || s.contains("${PWD}")
+}
+
+/// check_pwd_expansion reports PWD_EXPANSION violations.
+fn check_pwd_expansion(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n: _, op: _, v } => contains_pwd(v),
+            ast::Ore::Ru { dc: _, os: _, ps, ts: _, cs } => {
+                ps.iter().any(|e2| contains_pwd(e2)) || cs.iter().any(|e2| contains_pwd(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PWD_EXPANSION.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_pwd_expansion() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nROOT = $(PWD)\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PWD_EXPANSION.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:;echo ${PWD}\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PWD_EXPANSION.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo \"$$PWD\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PWD_EXPANSION.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PWD_EXPANSION.to_string()));
+}
+
+pub static PIPELINE_MASKING: &str =
+    "PIPELINE_MASKING: pipelines discard upstream exit statuses and POSIX sh lacks pipefail; check failures explicitly";
+
+/// contains_unquoted_pipe reports whether a command
+/// contains a pipeline operator outside of quotes.
+///
+/// This heuristic may miss pipes hidden in subshells,
+/// backticks, or macro expansions.
+fn contains_unquoted_pipe(s: &str) -> bool {
+    let mut cs = s.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = cs.next() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+            None => match c {
+                '\'' | '"' => quote = Some(c),
+                '|' => {
+                    if cs.peek() == Some(&'|') {
+                        cs.next();
+                    } else {
+                        return true;
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    false
+}
+
+/// check_pipeline_masking reports PIPELINE_MASKING violations.
+fn check_pipeline_masking(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts: _,
+                cs,
+            } => cs.iter().any(|e2| contains_unquoted_pipe(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PIPELINE_MASKING.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_pipeline_masking() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:;foo | tee log\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PIPELINE_MASKING.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;foo || bar\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PIPELINE_MASKING.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo \"a|b\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PIPELINE_MASKING.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo 'a|b'\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PIPELINE_MASKING.to_string()));
+}
+
+pub static NONPORTABLE_ECHO_FLAG: &str =
+    "NONPORTABLE_ECHO_FLAG: echo flags like -e and -n vary across sh implementations; use printf";
+
+/// strip_quoted_regions removes single and double quoted spans,
+/// leaving only the unquoted shell syntax.
+fn strip_quoted_regions(s: &str) -> String {
+    let mut out: String = String::new();
+    let mut quote: Option<char> = None;
+
+    for c in s.chars() {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                    out.push(' ');
+                }
+            }
+            None => match c {
+                '\'' | '"' => quote = Some(c),
+                _ => out.push(c),
+            },
+        }
+    }
+
+    out
+}
+
+/// has_flagged_echo reports whether a command invokes echo with flags,
+/// matching echo strictly in command word position.
+fn has_flagged_echo(command: &str) -> bool {
+    strip_quoted_regions(command)
+        .split([';', '|', '&', '(', ')'])
+        .any(|simple| {
+            let mut tokens = simple.split_whitespace();
+
+            tokens
+                .next()
+                .is_some_and(|e| e.trim_start_matches(['@', '+', '-']) == "echo")
+                && tokens.next().is_some_and(|e| e.starts_with('-'))
+        })
+}
+
+/// check_nonportable_echo_flag reports NONPORTABLE_ECHO_FLAG violations.
+fn check_nonportable_echo_flag(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts: _,
+                cs,
+            } => cs.iter().any(|e2| has_flagged_echo(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: NONPORTABLE_ECHO_FLAG.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_nonportable_echo_flag() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:;echo -n hello\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_ECHO_FLAG.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:\n\t@true && echo -e \"a\\tb\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_ECHO_FLAG.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo hello\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_ECHO_FLAG.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echoserver -n 1\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_ECHO_FLAG.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;grep \"echo -n\" foo.sh\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_ECHO_FLAG.to_string()));
+}
+
+pub static INTERACTIVE_COMMAND: &str =
+    "INTERACTIVE_COMMAND: commands blocking on interactive input hang non-interactive builds; supply answers up front";
+
+lazy_static::lazy_static! {
+    /// DEFAULT_INTERACTIVE_COMMANDS collects command names
+    /// that commonly block on interactive stdin input.
+    pub static ref DEFAULT_INTERACTIVE_COMMANDS: Vec<&'static str> = vec![
+        "ed",
+        "less",
+        "more",
+        "nano",
+        "passwd",
+        "read",
+        "vi",
+        "vim",
+    ];
+}
+
+/// blocks_on_stdin reports whether a command likely awaits
+/// interactive input, per the given interactive command list.
+fn blocks_on_stdin(command: &str, interactive_commands: &[&str]) -> bool {
+    for simple in strip_quoted_regions(command).split([';', '|', '&', '(', ')']) {
+        let tokens: Vec<&str> = simple.split_whitespace().collect();
+
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let command_word: &str = tokens[0].trim_start_matches(['@', '+', '-']);
+
+        if interactive_commands.contains(&command_word) {
+            return true;
+        }
+
+        if ["cp", "mv", "rm"].contains(&command_word) && tokens.contains(&"-i") {
+            return true;
+        }
+
+        if ["apt", "apt-get", "dnf", "yum"].contains(&command_word)
+            && tokens.contains(&"install")
+            && !tokens
+                .iter()
+                .any(|e| ["-y", "--yes", "--assume-yes", "--assumeyes"].contains(e))
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// check_interactive_command_with reports INTERACTIVE_COMMAND violations,
+/// per a custom interactive command list.
+pub fn check_interactive_command_with(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+    interactive_commands: &[&str],
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts: _,
+                cs,
+            } => cs.iter().any(|e2| blocks_on_stdin(e2, interactive_commands)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: INTERACTIVE_COMMAND.to_string(),
+        })
+        .collect()
+}
+
+/// check_interactive_command reports INTERACTIVE_COMMAND violations
+/// against [DEFAULT_INTERACTIVE_COMMANDS].
+fn check_interactive_command(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_interactive_command_with(metadata, gems, &DEFAULT_INTERACTIVE_COMMANDS)
+}
+
+#[test]
+fn test_interactive_command() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:;read answer\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INTERACTIVE_COMMAND.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\ndeps:;apt-get install curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INTERACTIVE_COMMAND.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nclean:;rm -i foo\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INTERACTIVE_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\ndeps:;apt-get install -y curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INTERACTIVE_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nclean:;rm -f foo\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INTERACTIVE_COMMAND.to_string()));
+
+    assert!(!check_interactive_command_with(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:;fancy-wizard\n").unwrap().ns,
+        &["fancy-wizard"],
+    )
+    .is_empty());
+}
+
+lazy_static::lazy_static! {
+    /// NONPORTABLE_FUNCTIONS collects make macro function names
+    /// specific to GNU or BSD implementations,
+    /// including the GNU introspection functions origin, flavor, and value.
+    pub static ref NONPORTABLE_FUNCTIONS: Vec<&'static str> = vec![
+        "abspath",
+        "addprefix",
+        "addsuffix",
+        "call",
+        "eval",
+        "filter",
+        "filter-out",
+        "flavor",
+        "foreach",
+        "origin",
+        "patsubst",
+        "realpath",
+        "shell",
+        "value",
+        "wildcard",
+    ];
+}
+
+pub static NONPORTABLE_FUNCTION: &str =
+    "NONPORTABLE_FUNCTION: make macro functions like $(shell ...) are GNU/BSD extensions";
+
+/// contains_nonportable_function reports whether a string
+/// invokes a GNU or BSD specific make macro function.
+fn contains_nonportable_function(s: &str) -> bool {
+    NONPORTABLE_FUNCTIONS
+        .iter()
+        .any(|f| s.contains(&format!("$({} ", f)) || s.contains(&format!("${{{} ", f)))
+}
+
+/// check_nonportable_function reports NONPORTABLE_FUNCTION violations.
+fn check_nonportable_function(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n: _, op: _, v } => contains_nonportable_function(v),
+            ast::Ore::Ru { dc: _, os: _, ps, ts: _, cs } => {
+                ps.iter().any(|e2| contains_nonportable_function(e2))
+                    || cs.iter().any(|e2| contains_nonportable_function(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: NONPORTABLE_FUNCTION.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_nonportable_function() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nSRC = $(wildcard *.c)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_FUNCTION.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nORIGIN = ${origin CC}\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_FUNCTION.to_string()));
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nall:;echo $(flavor CC)\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&NONPORTABLE_FUNCTION.to_string())
+    );
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nSRC = $(PKG)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NONPORTABLE_FUNCTION.to_string()));
+}
+
+lazy_static::lazy_static! {
+    /// FILESYSTEM_FUNCTIONS collects GNU macro functions
+    /// whose expansions depend on directory contents at parse time.
+    pub static ref FILESYSTEM_FUNCTIONS: Vec<&'static str> = vec![
+        "realpath",
+        "wildcard",
+    ];
+}
+
+pub static WILDCARD_EXPANSION: &str = "WILDCARD_EXPANSION: filesystem-dependent expansions like $(wildcard) weaken build reproducibility; declare explicit file lists";
+
+/// contains_filesystem_function searches a string
+/// for filesystem-dependent macro function expansions.
+fn contains_filesystem_function(s: &str) -> bool {
+    FILESYSTEM_FUNCTIONS
+        .iter()
+        .any(|f| s.contains(&format!("$({} ", f)) || s.contains(&format!("${{{} ", f)))
+}
+
+/// check_wildcard_expansion reports WILDCARD_EXPANSION violations.
+fn check_wildcard_expansion(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n: _, op: _, v } => contains_filesystem_function(v),
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts: _,
+                cs: _,
+            } => ps.iter().any(|e2| contains_filesystem_function(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: WILDCARD_EXPANSION.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_wildcard_expansion() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nSRC = $(wildcard *.c)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WILDCARD_EXPANSION.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nSRC = foo.c bar.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WILDCARD_EXPANSION.to_string()));
+}
+
+lazy_static::lazy_static! {
+    /// WELL_KNOWN_MACROS collects macro names
+    /// commonly preset by make implementations or the environment.
+    pub static ref WELL_KNOWN_MACROS: HashSet<&'static str> = vec![
+        "AR",
+        "ARFLAGS",
+        "CC",
+        "CFLAGS",
+        "CPPFLAGS",
+        "CXX",
+        "CXXFLAGS",
+        "FC",
+        "FFLAGS",
+        "GET",
+        "GFLAGS",
+        "LDFLAGS",
+        "LDLIBS",
+        "LEX",
+        "LFLAGS",
+        "MAKEFLAGS",
+        "SCCSFLAGS",
+        "SCCSGETFLAGS",
+        "SHELL",
+        "YACC",
+        "YFLAGS",
+    ]
+    .into_iter()
+    .collect::<HashSet<&'static str>>();
+}
+
+pub static APPEND_UNDEFINED_MACRO: &str =
+    "APPEND_UNDEFINED_MACRO: += on an undefined macro varies across make implementations";
+
+/// check_append_undefined_macro reports APPEND_UNDEFINED_MACRO violations.
+fn check_append_undefined_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut defined_macros: HashSet<&String> = HashSet::new();
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for gem in gems {
+        if let ast::Ore::Mc { n, op, v: _ } = &gem.n {
+            if op == "+="
+                && !defined_macros.contains(n)
+                && !WELL_KNOWN_MACROS.contains(&n.as_str())
+            {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: gem.l,
+                    offset: gem.o,
+                    message: APPEND_UNDEFINED_MACRO.to_string(),
+                });
+            }
+
+            defined_macros.insert(n);
+        }
+    }
+
+    warnings
+}
+
+#[test]
+fn test_append_undefined_macro() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nFLAGS += -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&APPEND_UNDEFINED_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nFLAGS = -O2\nFLAGS += -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&APPEND_UNDEFINED_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nCFLAGS += -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&APPEND_UNDEFINED_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nFLAGS ?= -O2\nFLAGS += -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&APPEND_UNDEFINED_MACRO.to_string()));
+}
+
+pub static RECURSIVE_MACRO: &str =
+    "RECURSIVE_MACRO: = macro referencing its own name expands infinitely";
+
+/// check_recursive_macro reports RECURSIVE_MACRO violations.
+///
+/// += appends honor the prior value,
+/// so only plain = assignments are flagged.
+fn check_recursive_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n, op, v } => {
+                op == "="
+                    && (v.contains(&format!("$({})", n)) || v.contains(&format!("${{{}}}", n)))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: RECURSIVE_MACRO.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_recursive_macro() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nFLAGS = $(FLAGS) -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RECURSIVE_MACRO.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nFLAGS = ${FLAGS} -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RECURSIVE_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nFLAGS = -O2\nFLAGS += -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RECURSIVE_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nFLAGS = $(CFLAGS) -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RECURSIVE_MACRO.to_string()));
+}
+
+lazy_static::lazy_static! {
+    /// EXTERNAL_MACROS collects macro names conventionally overridden
+    /// by end users on the make command line,
+    /// such as installation directories.
+    pub static ref EXTERNAL_MACROS: HashSet<&'static str> = vec![
+        "BINDIR",
+        "DESTDIR",
+        "LIBDIR",
+        "MANDIR",
+        "PREFIX",
+    ]
+    .into_iter()
+    .collect::<HashSet<&'static str>>();
+}
+
+pub static UNUSED_MACRO: &str = "UNUSED_MACRO: macro is defined but never referenced";
+
+/// references_macro reports whether the given text
+/// expands the named macro.
+fn references_macro(s: &str, name: &str) -> bool {
+    s.contains(&format!("$({})", name))
+        || s.contains(&format!("$({}:", name))
+        || s.contains(&format!("${{{}}}", name))
+        || s.contains(&format!("${{{}:", name))
+        || (name.len() == 1 && s.contains(&format!("${}", name)))
+}
+
+/// check_unused_macro reports UNUSED_MACRO violations.
+fn check_unused_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    if metadata.is_include_file {
+        return Vec::new();
+    }
+
+    let mut sites: Vec<(Option<&String>, &String)> = Vec::new();
+
+    for gem in gems {
+        match &gem.n {
+            ast::Ore::Mc { n, op: _, v } => sites.push((Some(n), v)),
+            ast::Ore::Ru {
+                dc: _,
+                os,
+                ps,
+                ts,
+                cs,
+            } => {
+                for s in ts.iter().chain(ps).chain(os).chain(cs) {
+                    sites.push((None, s));
+                }
+            }
+            ast::Ore::In { ps } => {
+                for s in ps {
+                    sites.push((None, s));
+                }
+            }
+            ast::Ore::Ex { e } => sites.push((None, e)),
+            _ => {}
+        }
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n, op: _, v: _ } => {
+                !n.contains('$')
+                    && !WELL_KNOWN_MACROS.contains(&n.as_str())
+                    && !READONLY_MACROS.contains(&n.as_str())
+                    && !EXTERNAL_MACROS.contains(&n.as_str())
+                    && !sites
+                        .iter()
+                        .any(|(owner, s)| *owner != Some(n) && references_macro(s, n))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: UNUSED_MACRO.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_unused_macro() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nFLAGS = -g\nall:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNUSED_MACRO.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nFLAGS = -g\nall:\n\tcc $(FLAGS) -o all all.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&UNUSED_MACRO.to_string())
+    );
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nFLAGS = -g\nall:\n\tcc ${FLAGS} -o all all.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&UNUSED_MACRO.to_string())
+    );
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nOBJS = all.o\nSRCS = $(OBJS:.o=.c)\nall: $(SRCS)\n\techo done\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&UNUSED_MACRO.to_string())
+    );
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPREFIX = /usr/local\nall:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNUSED_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nCFLAGS = -O2\nall:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNUSED_MACRO.to_string()));
+
+    let mut md_include: inspect::Metadata = mock_md("foo.include.mk");
+    md_include.is_include_file = true;
+
+    assert!(!lint(&md_include, "FLAGS = -g\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNUSED_MACRO.to_string()));
+}
+
+pub static UNDEFINED_MACRO: &str =
+    "UNDEFINED_MACRO: macro is referenced but never defined, a common silent typo";
+
+/// macro_references collects the names of any macros
+/// expanded with $(...) or ${...} syntax in the given text.
+fn macro_references(s: &str) -> Vec<String> {
+    let mut references: Vec<String> = Vec::new();
+    let mut cs = s.chars().peekable();
+
+    while let Some(c) = cs.next() {
+        if c != '$' {
+            continue;
+        }
+
+        // Skip shell escapes ($$) and internal macros ($@, $<, etc.)
+        if let Some(open @ ('(' | '{')) = cs.next() {
+            let closer: char = if open == '(' { ')' } else { '}' };
+            let mut name: String = String::new();
+            let mut terminated: bool = false;
+
+            for c2 in cs.by_ref() {
+                if c2 == closer || c2 == ':' {
+                    terminated = true;
+                    break;
+                }
+
+                name.push(c2);
+            }
+
+            if terminated
+                && !name.is_empty()
+                && name
+                    .chars()
+                    .all(|e| e.is_ascii_alphanumeric() || e == '_' || e == '.')
+            {
+                references.push(name);
+            }
+        }
+    }
+
+    references
+}
+
+/// check_undefined_macro reports UNDEFINED_MACRO violations.
+fn check_undefined_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    if metadata.is_include_file {
+        return Vec::new();
+    }
+
+    // Included files may supply further definitions.
+    if gems
+        .iter()
+        .any(|e| matches!(&e.n, ast::Ore::In { ps: _ }))
+    {
+        return Vec::new();
+    }
+
+    let mut defined_macros: HashSet<&String> = HashSet::new();
+
+    for gem in gems {
+        if let ast::Ore::Mc { n, op: _, v: _ } = &gem.n {
+            defined_macros.insert(n);
+        }
+    }
+
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for gem in gems {
+        let mut texts: Vec<&String> = Vec::new();
+
+        match &gem.n {
+            ast::Ore::Mc { n: _, op: _, v } => texts.push(v),
+            ast::Ore::Ru {
+                dc: _,
+                os,
+                ps,
+                ts,
+                cs,
+            } => texts.extend(ts.iter().chain(ps).chain(os).chain(cs)),
+            ast::Ore::Ex { e } => texts.push(e),
+            _ => {}
+        }
+
+        if texts
+            .iter()
+            .flat_map(|e| macro_references(e))
+            .any(|name| {
+                !defined_macros.contains(&name)
+                    && !WELL_KNOWN_MACROS.contains(&name.as_str())
+                    && !READONLY_MACROS.contains(&name.as_str())
+                    && !EXTERNAL_MACROS.contains(&name.as_str())
+            })
+        {
+            warnings.push(Warning {
+                path: metadata.path.to_string(),
+                line: gem.l,
+                offset: gem.o,
+                message: UNDEFINED_MACRO.to_string(),
+            });
+        }
+    }
+
+    warnings
+}
+
+#[test]
+fn test_undefined_macro() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:\n\techo $(FLAGS)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNDEFINED_MACRO.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nFLAGS = -g\nall:\n\techo $(FLAGS)\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&UNDEFINED_MACRO.to_string())
+    );
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:\n\tcc $(CFLAGS) -o all all.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNDEFINED_MACRO.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:\n\techo $$(ls)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNDEFINED_MACRO.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\ninclude deps.mk\nall:\n\techo $(FLAGS)\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&UNDEFINED_MACRO.to_string())
+    );
+
+    let mut md_include: inspect::Metadata = mock_md("foo.include.mk");
+    md_include.is_include_file = true;
+
+    assert!(!lint(&md_include, "all:\n\techo $(FLAGS)\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNDEFINED_MACRO.to_string()));
+}
+
+pub static SHELL_ASSIGNMENT: &str =
+    "SHELL_ASSIGNMENT: != macro assignments run commands at parse time, varying across environments";
+
+/// check_shell_assignment reports SHELL_ASSIGNMENT violations.
+fn check_shell_assignment(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n: _, op, v: _ } => op == "!=",
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SHELL_ASSIGNMENT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_shell_assignment() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nTIMESTAMP != date\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&SHELL_ASSIGNMENT.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&SHELL_ASSIGNMENT.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG ?= curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&SHELL_ASSIGNMENT.to_string()));
+}
+
+pub static WD_NOP: &str =
+    "WD_NOP: change directory commands may not persist across successive commands or rules";
+
+/// check_wd_nop reports WD_NOP violations.
+fn check_wd_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => cs.iter().enumerate().any(|(i, e2)| {
+                WD_COMMANDS.contains(&e2.split_whitespace().next().unwrap_or(""))
+                    && !e2.contains("&&")
+                    && !e2.contains(';')
+                    && !e2.contains('|')
+                    && i < cs.len() - 1
+                    && !is_make_invocation(cs[i + 1].trim_start_matches(['@', '-', '+']))
+            }),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: WD_NOP.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_wd_nop() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo\n\n\tpushd bar\n\tpopd\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&WD_NOP.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\ttar -C foo czvf foo.tgz .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&WD_NOP.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo && ./build.sh\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&WD_NOP.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo; ./build.sh\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&WD_NOP.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\n.PHONY: all\nall:\n\tcd foo\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WD_NOP.to_string()));
+}
+
+pub static MAKE_AFTER_CD: &str =
+    "MAKE_AFTER_CD: replace cd <dir> before a make invocation with $(MAKE) -C <dir>";
+
+/// is_make_invocation reports whether a shell snippet launches make.
+fn is_make_invocation(s: &str) -> bool {
+    ["make", "$(MAKE)", "${MAKE}"].contains(&s.split_whitespace().next().unwrap_or(""))
+}
+
+/// check_make_after_cd reports MAKE_AFTER_CD violations.
+fn check_make_after_cd(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => cs.iter().enumerate().any(|(i, e2)| {
+                let command: &str = e2.trim_start_matches(['@', '-', '+']);
+
+                match command.strip_prefix("cd ") {
+                    None => false,
+                    Some(rest) => {
+                        let chained: Option<&str> = match (rest.find("&&"), rest.find(';')) {
+                            (Some(a), Some(s)) if s < a => Some(&rest[1 + s..]),
+                            (Some(a), _) => Some(&rest[2 + a..]),
+                            (None, Some(s)) => Some(&rest[1 + s..]),
+                            (None, None) => None,
+                        };
+
+                        match chained {
+                            Some(c) => is_make_invocation(c.trim()),
+                            None => {
+                                i < cs.len() - 1
+                                    && is_make_invocation(
+                                        cs[i + 1].trim_start_matches(['@', '-', '+']),
+                                    )
+                            }
+                        }
+                    }
+                }
+            }),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: MAKE_AFTER_CD.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_make_after_cd() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo && $(MAKE) install\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MAKE_AFTER_CD.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo\n\tmake install\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MAKE_AFTER_CD.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo; make install\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MAKE_AFTER_CD.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\t$(MAKE) -C foo install\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MAKE_AFTER_CD.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall:\n\tcd foo && ./build.sh\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MAKE_AFTER_CD.to_string()));
+}
+
+pub static WAIT_NOP: &str = "WAIT_NOP: .WAIT as a target has no effect";
+
+/// check_makefile_precedence reports WAIT_NOP violations.
+fn check_wait_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => ts.contains(&".WAIT".to_string()),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: WAIT_NOP.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_wait_nop() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n.WAIT:\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WAIT_NOP.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&WAIT_NOP.to_string()));
+}
+
+pub static PHONY_NOP: &str = "PHONY_NOP: empty .PHONY has no effect";
+
+/// check_phony_nop reports PHONY_NOP violations.
+fn check_phony_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => ts.contains(&".PHONY".to_string()) && ps.is_empty(),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PHONY_NOP.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_phony_nop() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY:\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_NOP.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: test\ntest:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_NOP.to_string()));
+}
+
+pub static DEFAULT_NOP: &str = "DEFAULT_NOP: .DEFAULT without commands has no effect";
+
+/// check_default_nop reports DEFAULT_NOP violations.
+fn check_default_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                ts.contains(&".DEFAULT".to_string()) && cs.is_empty()
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: DEFAULT_NOP.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_default_nop() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n.DEFAULT:;\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&DEFAULT_NOP.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.DEFAULT:\n\techo \"no rule for target\"\nall:;echo done\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&DEFAULT_NOP.to_string()));
+}
+
+pub static PHONY_PATH: &str =
+    "PHONY_PATH: phony targets should be logical names, not file paths";
+
+/// check_phony_path reports PHONY_PATH violations.
+fn check_phony_path(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => {
+                ts.contains(&".PHONY".to_string()) && ps.iter().any(|e2| e2.contains('/'))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PHONY_PATH.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_phony_path() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: build/app\nbuild/app: app.c\n\tgcc -o build/app app.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_PATH.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall: build/app\nbuild/app: app.c\n\tgcc -o build/app app.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_PATH.to_string()));
+}
+
+pub static REDUNDANT_NOTPARALLEL_WAIT: &str =
+    "REDUNDANT_NOTPARALLEL_WAIT: .NOTPARALLEL with .WAIT is redundant and superfluous";
+
+/// check_redundant_notparallel_wait reports REDUNDANT_NOTPARALLEL_WAIT violations.
+fn check_redundant_notparallel_wait(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+) -> Vec<Warning> {
+    let has_notparallel: bool = gems.iter().any(|e| match &e.n {
+        ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => ts.contains(&".NOTPARALLEL".to_string()),
+        _ => false,
+    });
+
+    if !has_notparallel {
+        return Vec::new();
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts: _, cs: _ } => ps.contains(&".WAIT".to_string()),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: REDUNDANT_NOTPARALLEL_WAIT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_redundant_nonparallel_wait() {
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\n.NOTPARALLEL:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&REDUNDANT_NOTPARALLEL_WAIT.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&REDUNDANT_NOTPARALLEL_WAIT.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.NOTPARALLEL:\n.PHONY: test test-1 test-2\ntest: test-1 test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&REDUNDANT_NOTPARALLEL_WAIT.to_string()));
+}
+
+pub static LINEAR_WAIT: &str =
+    "LINEAR_WAIT: .WAIT separates prerequisites already ordered by their own dependency chain; remove the marker";
+
+/// depends_transitively reports whether start depends on goal,
+/// directly or through intermediate prerequisites.
+fn depends_transitively(
+    graph: &HashMap<&String, Vec<&String>>,
+    start: &String,
+    goal: &String,
+) -> bool {
+    let mut stack: Vec<&String> = vec![start];
+    let mut visited: HashSet<&String> = HashSet::new();
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+
+        if let Some(ps) = graph.get(node) {
+            for p in ps {
+                if *p == goal {
+                    return true;
+                }
+
+                stack.push(p);
+            }
+        }
+    }
+
+    false
+}
+
+/// check_linear_wait reports LINEAR_WAIT violations.
+fn check_linear_wait(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut graph: HashMap<&String, Vec<&String>> = HashMap::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru {
+            dc: _,
+            os: _,
+            ps,
+            ts,
+            cs: _,
+        } = &gem.n
+        {
+            for t in ts {
+                graph
+                    .entry(t)
+                    .or_default()
+                    .extend(ps.iter().filter(|e| e.as_str() != ".WAIT"));
+            }
+        }
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts: _,
+                cs: _,
+            } => ps.iter().enumerate().any(|(i, p)| {
+                p == ".WAIT"
+                    && i > 0
+                    && 1 + i < ps.len()
+                    && depends_transitively(&graph, &ps[1 + i], &ps[i - 1])
+            }),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: LINEAR_WAIT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_linear_wait() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nall: a .WAIT b\na:;echo a\nb: a\n\techo b\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LINEAR_WAIT.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nall: a .WAIT c\na:;echo a\nb: a\n\techo b\nc: b\n\techo c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LINEAR_WAIT.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nall: a .WAIT b\na:;echo a\nb:;echo b\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LINEAR_WAIT.to_string()));
+}
+
+pub static REDUNDANT_SILENT_AT: &str =
+    "REDUNDANT_SILENT_AT: .SILENT with @ is redundant and superfluous";
+
+/// check_redundant_silent_at reports REDUNDANT_SILENT_AT violations.
+fn check_redundant_silent_at(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut has_global_silence: bool = false;
+    let mut marked_silent_targets: HashSet<&String> = HashSet::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".SILENT".to_string()) {
+                if ps.is_empty() {
+                    has_global_silence = true;
+                }
+
+                for p in ps {
+                    marked_silent_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                cs.iter().any(|e2| e2.starts_with('@'))
+                    && (has_global_silence
+                        || ts.iter().any(|e2| marked_silent_targets.contains(e2)))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: REDUNDANT_SILENT_AT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_redundant_silent_at() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: lint\n.SILENT:\nlint:\n\t@unmake .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REDUNDANT_SILENT_AT.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: lint\n.SILENT: lint\nlint:\n\t@unmake .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REDUNDANT_SILENT_AT.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: lint\n.SILENT: lint\nlint:\n\tunmake .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REDUNDANT_SILENT_AT.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.PHONY: lint\nlint:\n\t@unmake .\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&REDUNDANT_SILENT_AT.to_string())
+    );
+}
+
+pub static REDUNDANT_IGNORE_MINUS: &str =
+    "REDUNDANT_IGNORE_MINUS: .IGNORE with - is redundant and superfluous";
+
+/// check_redundant_ignore_minus reports REDUNDANT_IGNORE_MINUS violations.
+fn check_redundant_ignore_minus(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut marked_ignored_targets: HashSet<&String> = HashSet::new();
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".IGNORE".to_string()) {
+                for p in ps {
+                    marked_ignored_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                cs.iter().any(|e2| e2.starts_with('-'))
+                    && ts.iter().any(|e2| marked_ignored_targets.contains(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: REDUNDANT_IGNORE_MINUS.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_redundant_ignore_minus() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\n.IGNORE: clean\nclean:\n\t-rm -rf bin\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REDUNDANT_IGNORE_MINUS.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REDUNDANT_IGNORE_MINUS.to_string()));
+}
+
+pub static LAYERED_SILENCE: &str =
+    "LAYERED_SILENCE: global and per-target .SILENT or .IGNORE declarations layer redundantly";
+
+/// check_layered_silence reports LAYERED_SILENCE violations.
+///
+/// REDUNDANT_SILENT_AT and REDUNDANT_IGNORE_MINUS cover declarations
+/// layered with command prefixes, so this check focuses on global
+/// declarations layered with per-target declarations.
+fn check_layered_silence(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut global_marks: HashSet<&'static str> = HashSet::new();
+    let mut marked_targets: HashSet<(&'static str, &String)> = HashSet::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            for special in [".SILENT", ".IGNORE"] {
+                if ts.contains(&special.to_string()) {
+                    if ps.is_empty() {
+                        global_marks.insert(special);
+                    }
+
+                    for p in ps {
+                        marked_targets.insert((special, p));
+                    }
+                }
+            }
+        }
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                !cs.is_empty()
+                    && global_marks.iter().any(|special| {
+                        ts.iter().any(|e2| marked_targets.contains(&(*special, e2)))
+                    })
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: LAYERED_SILENCE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_layered_silence() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: lint\n.SILENT:\n.SILENT: lint\nlint:\n\t@unmake .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LAYERED_SILENCE.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.IGNORE:\n.IGNORE: clean\nclean:\n\trm -rf bin\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LAYERED_SILENCE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: lint\n.SILENT:\nlint:\n\tunmake .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LAYERED_SILENCE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: lint\n.SILENT: lint\nlint:\n\tunmake .\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LAYERED_SILENCE.to_string()));
+}
+
+pub static GLOBAL_IGNORE: &str =
+    "GLOBAL_IGNORE: .IGNORE without prerequisites may corrupt artifacts";
+
+/// check_global_ignore reports GLOBAL_IGNORE violations.
+fn check_global_ignore(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => ts.contains(&".IGNORE".to_string()) && ps.is_empty(),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: GLOBAL_IGNORE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_global_ignore() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n.IGNORE:\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&GLOBAL_IGNORE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\n.IGNORE: clean\nclean:\n\trm -rf bin"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&GLOBAL_IGNORE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&GLOBAL_IGNORE.to_string()));
+}
+
+pub static SOFTEN_CLEAN: &str = "SOFTEN_CLEAN: prefix removal commands in clean rules with hyphen-minus (-), or declare .IGNORE: clean, so cleanup proceeds past missing files";
+
+/// check_soften_clean reports SOFTEN_CLEAN violations.
+fn check_soften_clean(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut has_global_ignore: bool = false;
+    let mut marked_ignored_targets: HashSet<&String> = HashSet::new();
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".IGNORE".to_string()) {
+                if ps.is_empty() {
+                    has_global_ignore = true;
+                }
+
+                for p in ps {
+                    marked_ignored_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    if has_global_ignore {
+        return Vec::new();
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                ts.iter().any(|e2| {
+                    LOWER_CLEAN_TARGETS_PATTERN.is_match(e2.to_lowercase().as_str())
+                        && !marked_ignored_targets.contains(e2)
+                }) && cs.iter().any(|e2| {
+                    e2.trim_start_matches(['@', '+'])
+                        .trim_start()
+                        .starts_with("rm")
+                })
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SOFTEN_CLEAN.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_soften_clean() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\nclean:\n\trm -rf bin\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SOFTEN_CLEAN.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SOFTEN_CLEAN.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: clean\n.IGNORE: clean\nclean:\n\trm -rf bin\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SOFTEN_CLEAN.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.PHONY: all\nall:\n\techo done\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&SOFTEN_CLEAN.to_string())
+    );
+}
+
+pub static SIMPLIFY_AT: &str =
+    "SIMPLIFY_AT: replace individual at (@) signs with .SILENT target declaration(s)";
+
+/// check_simplify_at reports SIMPLIFY_AT violations.
+fn check_simplify_at(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut has_global_silence: bool = false;
+    let mut marked_silent_targets: HashSet<&String> = HashSet::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".SILENT".to_string()) {
+                if ps.is_empty() {
+                    has_global_silence = true;
+                }
+
+                for p in ps {
+                    marked_silent_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    if has_global_silence {
+        return Vec::new();
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                cs.len() > 1
+                    && cs.iter().all(|e2| e2.starts_with('@'))
+                    && !ts.iter().any(|e2| marked_silent_targets.contains(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SIMPLIFY_AT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_simplify_at() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\t@echo foo\n\t@echo bar\n\t@echo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMPLIFY_AT.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\t@echo foo\n\t@echo bar\n\techo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMPLIFY_AT.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.SILENT: welcome\nwelcome:\n\techo foo\n\techo bar\n\techo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMPLIFY_AT.to_string()));
+}
+
+pub static SIMPLIFY_MINUS: &str =
+    "SIMPLIFY_MINUS: replace individual hyphen-minus (-) signs with .IGNORE target declaration(s)";
+
+/// check_simplify_minus reports SIMPLIFY_MINUS violations.
+fn check_simplify_minus(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut has_global_ignore: bool = false;
+    let mut marked_ignored_targets: HashSet<&String> = HashSet::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".IGNORE".to_string()) {
+                if ps.is_empty() {
+                    has_global_ignore = true;
+                }
+
+                for p in ps {
+                    marked_ignored_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    if has_global_ignore {
+        return Vec::new();
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                cs.len() > 1
+                    && cs.iter().all(|e2| e2.starts_with('-'))
+                    && !ts.iter().any(|e2| marked_ignored_targets.contains(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SIMPLIFY_MINUS.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_simplify_minus() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\t-echo foo\n\t-echo bar\n\t-echo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMPLIFY_MINUS.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\t-echo foo\n\t-echo bar\n\techo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMPLIFY_MINUS.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.IGNORE: welcome\nwelcome:\n\techo foo\n\techo bar\n\techo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMPLIFY_MINUS.to_string()));
+}
+
+pub static IGNORED_SOLE_COMMAND: &str =
+    "IGNORED_SOLE_COMMAND: a sole hyphen-minus (-) prefixed command masks all failures of the rule";
+
+/// check_ignored_sole_command reports IGNORED_SOLE_COMMAND violations.
+///
+/// Targets named clean* are exempt,
+/// as ignoring removal failures there is idiomatic.
+fn check_ignored_sole_command(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts,
+                cs,
+            } => {
+                cs.len() == 1
+                    && cs[0].trim_start_matches(['@', '+']).starts_with('-')
+                    && !ts.iter().any(|e2| {
+                        e2.starts_with('.') || e2.to_lowercase().starts_with("clean")
+                    })
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: IGNORED_SOLE_COMMAND.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_ignored_sole_command() {
+    assert!(lint(&mock_md("-"), ".POSIX:\ninstall:\n\t-cp all /usr/local/bin\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&IGNORED_SOLE_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\ninstall:\n\tcp all /usr/local/bin\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&IGNORED_SOLE_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nclean:\n\t-rm -f all\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&IGNORED_SOLE_COMMAND.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nall:\n\t-echo foo\n\techo bar\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&IGNORED_SOLE_COMMAND.to_string())
+    );
+}
+
+pub static INCONSISTENT_SILENCE: &str =
+    "INCONSISTENT_SILENCE: rules mixing at (@) silenced and unsilenced commands are likely oversights";
+
+/// check_inconsistent_silence reports INCONSISTENT_SILENCE violations.
+fn check_inconsistent_silence(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut has_global_silence: bool = false;
+    let mut marked_silent_targets: HashSet<&String> = HashSet::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".SILENT".to_string()) {
+                if ps.is_empty() {
+                    has_global_silence = true;
+                }
+
+                for p in ps {
+                    marked_silent_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    if has_global_silence {
+        return Vec::new();
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                cs.len() > 1
+                    && cs.iter().any(|e2| e2.starts_with('@'))
+                    && cs.iter().any(|e2| !e2.starts_with('@'))
+                    && !ts.iter().any(|e2| marked_silent_targets.contains(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: INCONSISTENT_SILENCE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_inconsistent_silence() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\t@echo foo\n\t@echo bar\n\techo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_SILENCE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\t@echo foo\n\t@echo bar\n\t@echo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_SILENCE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nwelcome:\n\techo foo\n\techo bar\n\techo baz\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_SILENCE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.SILENT:\nwelcome:\n\t@echo foo\n\techo bar\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_SILENCE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.SILENT: welcome\nwelcome:\n\t@echo foo\n\techo bar\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_SILENCE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nwelcome:\n\t@echo foo\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INCONSISTENT_SILENCE.to_string()));
+}
+
+pub static STRICT_POSIX: &str =
+    "STRICT_POSIX: lead makefiles with the \".POSIX:\" compliance marker, or else rename include files like *.include.mk";
+
+/// check_strict_posix reports STRICT_POSIX violations.
+fn check_strict_posix(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    if metadata.is_include_file {
+        return Vec::new();
+    }
+
+    let has_strict_posix: bool = gems.iter().any(|e| match &e.n {
+        ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => ts.contains(&".POSIX".to_string()),
+        _ => false,
+    });
+
+    if !has_strict_posix {
+        return vec![Warning {
+            path: metadata.path.to_string(),
+            line: 1,
+            offset: 0,
+            message: STRICT_POSIX.to_string(),
+        }];
+    }
+
+    Vec::new()
+}
+
+#[test]
+pub fn test_strict_posix() {
+    let md_stdin: inspect::Metadata = mock_md("-");
+
+    assert!(lint(&md_stdin, "PKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&STRICT_POSIX.to_string()));
+
+    assert!(!lint(&md_stdin, ".POSIX:\nPKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&STRICT_POSIX.to_string()));
+
+    let mut md_sys: inspect::Metadata = mock_md("sys.mk");
+    md_sys.is_include_file = true;
+
+    assert!(!lint(&md_sys, "PKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&STRICT_POSIX.to_string()));
+
+    let mut md_include_mk: inspect::Metadata = mock_md("foo.include.mk");
+    md_include_mk.is_include_file = true;
+
+    assert!(!lint(&md_include_mk, "PKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&STRICT_POSIX.to_string()));
+}
+
+pub static POSIX_CONTRADICTION: &str =
+    "POSIX_CONTRADICTION: .POSIX declared, yet the makefile relies on non-POSIX behavior";
+
+/// escalate_posix_contradictions appends a POSIX_CONTRADICTION companion
+/// warning for each portability-class warning when a makefile declares
+/// .POSIX, as the author explicitly claimed strict compliance.
+///
+/// Toggle the escalation off by disabling the POSIX_CONTRADICTION
+/// rule id via configuration or inline suppression comments.
+fn escalate_posix_contradictions(gems: &[ast::Gem], warnings: &mut Vec<Warning>) {
+    let posix_declared: bool = gems.iter().any(|e| match &e.n {
+        ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => ts.contains(&".POSIX".to_string()),
+        _ => false,
+    });
+
+    if !posix_declared {
+        return;
+    }
+
+    let escalations: Vec<Warning> = warnings
+        .iter()
+        .filter(|e| PORTABILITY_RULE_IDS.contains(&rule_id(&e.message)))
+        .map(|e| Warning {
+            path: e.path.clone(),
+            line: e.line,
+            offset: e.offset,
+            message: POSIX_CONTRADICTION.to_string(),
+        })
+        .collect();
+
+    warnings.extend(escalations);
+}
+
+#[test]
+pub fn test_posix_contradiction() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall:;echo ${MAKECMDGOALS}\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&POSIX_CONTRADICTION.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nMAKEFLAGS = -j\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&POSIX_CONTRADICTION.to_string()));
+
+    assert!(!lint(&mock_md("-"), "all:;echo ${MAKECMDGOALS}\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&POSIX_CONTRADICTION.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&POSIX_CONTRADICTION.to_string()));
+}
+
+pub static IMPLEMENTATTION_DEFINED_TARGET: &str = "IMPLEMENTATTION_DEFINED_TARGET: non-portable percent (%) or double-quote (\") in target or prerequisite";
+
+/// check_implementation_defined_target reports IMPLEMENTATTION_DEFINED_TARGET violations.
+fn check_implementation_defined_target(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => {
+                ps.iter().any(|e2| e2.contains('%') || e2.contains('\"'))
+                    || ts.iter().any(|e2| e2.contains('%') || e2.contains('\"'))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: IMPLEMENTATTION_DEFINED_TARGET.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_implementation_defined_target() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall: foo%\nfoo%: foo.c\n\tgcc -o foo% foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&IMPLEMENTATTION_DEFINED_TARGET.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall: \"foo\"\n\"foo\": foo.c\n\tgcc -o \"foo\" foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&IMPLEMENTATTION_DEFINED_TARGET.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall: foo\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&IMPLEMENTATTION_DEFINED_TARGET.to_string()));
+}
+
+pub static SHELL_METACHARACTER_TARGET: &str = "SHELL_METACHARACTER_TARGET: non-portable shell metacharacter (*, ?, [, ], or stray $) in target or prerequisite";
+
+/// contains_shell_metacharacter searches a target or prerequisite name
+/// for glob and shell metacharacters residing outside macro expansions.
+fn contains_shell_metacharacter(s: &str) -> bool {
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' | '?' | '[' | ']' => return true,
+            '$' => match chars.next() {
+                None => return true,
+                Some('(') => {
+                    while chars.next_if(|c2| *c2 != ')').is_some() {}
+                    chars.next();
+                }
+                Some('{') => {
+                    while chars.next_if(|c2| *c2 != '}').is_some() {}
+                    chars.next();
+                }
+                Some(_) => {}
+            },
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// check_shell_metacharacter_target reports SHELL_METACHARACTER_TARGET violations.
+fn check_shell_metacharacter_target(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => {
+                ps.iter().any(|e2| contains_shell_metacharacter(e2))
+                    || ts.iter().any(|e2| contains_shell_metacharacter(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SHELL_METACHARACTER_TARGET.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_shell_metacharacter_target() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall: foo.[ch]\nfoo.[ch]: foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SHELL_METACHARACTER_TARGET.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nall: foo.? foo.*\n\techo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&SHELL_METACHARACTER_TARGET.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nall: $(OBJS) ${LIBS}\n\techo done\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&SHELL_METACHARACTER_TARGET.to_string())
+    );
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\nall: foo\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SHELL_METACHARACTER_TARGET.to_string()));
+}
+
+pub static PATTERN_RULE: &str =
+    "PATTERN_RULE: GNU pattern rules are non-portable; declare a POSIX suffix rule";
+
+/// check_pattern_rule reports PATTERN_RULE violations.
+fn check_pattern_rule(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => {
+                ts.iter().any(|e2| e2.contains('%')) && ps.iter().any(|e2| e2.contains('%'))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PATTERN_RULE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_pattern_rule() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n%.o: %.c\n\t$(CC) -c $<\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PATTERN_RULE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall: foo%\n\techo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PATTERN_RULE.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.SUFFIXES: .c .o\n.c.o:\n\t$(CC) -c $<\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&PATTERN_RULE.to_string())
+    );
+}
+
+pub static GNU_VPATH: &str =
+    "GNU_VPATH: vpath directives and VPATH assignments are GNU extensions; spell out relative paths";
+
+/// check_gnu_vpath reports GNU_VPATH violations.
+fn check_gnu_vpath(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Vp { ps: _ } => true,
+            ast::Ore::Mc { n, op: _, v: _ } => n == "VPATH",
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: GNU_VPATH.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_gnu_vpath() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nvpath %.c src\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&GNU_VPATH.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nVPATH = src\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&GNU_VPATH.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall: src/foo.c\n\techo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&GNU_VPATH.to_string()));
+}
+
+pub static ORDER_ONLY_PREREQUISITE: &str =
+    "ORDER_ONLY_PREREQUISITE: order-only prerequisites (|) are a GNU extension";
+
+/// check_order_only_prereq reports ORDER_ONLY_PREREQUISITE violations.
+fn check_order_only_prereq(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os,
+                ps: _,
+                ts: _,
+                cs: _,
+            } => !os.is_empty(),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: ORDER_ONLY_PREREQUISITE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_order_only_prereq() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall: build | deps\n\techo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&ORDER_ONLY_PREREQUISITE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall: build deps\n\techo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&ORDER_ONLY_PREREQUISITE.to_string()));
+}
+
+pub static DOUBLE_COLON_RULE: &str =
+    "DOUBLE_COLON_RULE: double-colon rules (::) are a GNU/BSD extension";
+
+/// check_double_colon_rule reports DOUBLE_COLON_RULE violations.
+fn check_double_colon_rule(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc,
+                os: _,
+                ps: _,
+                ts: _,
+                cs: _,
+            } => *dc,
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: DOUBLE_COLON_RULE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_double_colon_rule() {
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nall:: foo.c\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&DOUBLE_COLON_RULE.to_string())
+    );
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nall: foo.c\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&DOUBLE_COLON_RULE.to_string())
+    );
+}
+
+pub static COMMAND_COMMENT: &str =
+    "COMMAND_COMMENT: comment embedded inside commands will forward to the shell interpreter";
+
+/// check_command_comment reports COMMAND_COMMENT violations.
+fn check_command_comment(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => cs.iter().any(|e2| e2.contains('#')),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: COMMAND_COMMENT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_command_comment() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: foo.c\n\t#build foo\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&COMMAND_COMMENT.to_string()));
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t@#gcc -o foo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&COMMAND_COMMENT.to_string())
+    );
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t-#gcc -o foo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&COMMAND_COMMENT.to_string())
+    );
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t+#gcc -o foo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&COMMAND_COMMENT.to_string())
+    );
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: foo.c\n\tgcc \\\n#output file \\\n\t\t-o foo \\\n\t\tfoo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&COMMAND_COMMENT.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: foo.c\n#build foo\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&COMMAND_COMMENT.to_string()));
+}
+
+pub static SHELL_COMMENT_ONLY_COMMAND: &str =
+    "SHELL_COMMENT_ONLY_COMMAND: pure comment command wastes a shell; promote to a make-level comment";
+
+/// check_shell_comment_only_command reports SHELL_COMMENT_ONLY_COMMAND violations.
+fn check_shell_comment_only_command(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => cs
+                .iter()
+                .any(|e2| e2.trim_start_matches(['@', '-', '+']).starts_with('#')),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SHELL_COMMENT_ONLY_COMMAND.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_shell_comment_only_command() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: foo.c\n\t#build foo\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SHELL_COMMENT_ONLY_COMMAND.to_string()));
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t@#build foo\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&SHELL_COMMENT_ONLY_COMMAND.to_string())
+    );
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: foo.c\n\tgcc -o foo foo.c #build foo\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SHELL_COMMENT_ONLY_COMMAND.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n#build foo\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SHELL_COMMENT_ONLY_COMMAND.to_string()));
+}
+
+pub static REPEATED_COMMAND_PREFIX: &str =
+    "REPEATED_COMMAND_PREFIX: redundant prefixes are superfluous";
+
+/// check_repeated_command_prefix reports REPEATED_COMMAND_PREFIX violations.
+fn check_repeated_command_prefix(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => cs.iter().any(|e2| {
+                if BLANK_COMMAND_PATTERN.is_match(e2) {
+                    return false;
+                }
+
+                let prefix: &str = COMMAND_PREFIX_PATTERN
+                    .captures(e2)
+                    .and_then(|e3| e3.name("prefix"))
+                    .map(|e3| e3.as_str())
+                    .unwrap_or("");
+
+                prefix.matches('@').count() > 1
+                    || prefix.matches('+').count() > 1
+                    || prefix.matches('-').count() > 1
+            }),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: REPEATED_COMMAND_PREFIX.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_repeated_command_prefix() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: test\ntest:\n\t@@echo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: test\ntest:\n\t--echo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: test\ntest:\n\t@-@echo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: test\ntest:\n\t@+-echo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+}
+
+pub static BLANK_COMMAND: &str =
+    "BLANK_COMMAND: indeterminate behavior when empty commands are sent to assorted shell interpreters";
+
+/// check_blank_command reports BLANK_COMMAND violations.
+fn check_blank_command(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => {
+                cs.iter().any(|e2| BLANK_COMMAND_PATTERN.is_match(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: BLANK_COMMAND.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_blank_command() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t@\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&BLANK_COMMAND.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t-\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&BLANK_COMMAND.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t+\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&BLANK_COMMAND.to_string()));
+
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t@+- \n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&BLANK_COMMAND.to_string())
+    );
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: test\ntest:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BLANK_COMMAND.to_string()));
+}
+
+pub static WHITESPACE_LEADING_COMMAND: &str =
+    "WHITESPACE_LEADING_COMMAND: questionable whitespace detected at the start of a command";
+
+/// check_whitespace_leading_command reports WHITESPACE_LEADING_COMMAND violations.
+fn check_whitespace_leading_command(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts: _, cs } => cs
+                .iter()
+                .any(|e2| WHITESPACE_LEADING_COMMAND_PATTERN.is_match(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: WHITESPACE_LEADING_COMMAND.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_whitespace_leading_command() {
+    assert!(lint(&mock_md("-"), "foo:\n\t gcc -o foo foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
+
+    assert!(lint(&mock_md("-"), "foo:\n\t\tgcc -o foo foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
+
+    assert!(lint(&mock_md("-"), "foo:\n\t@+- gcc -o foo foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), "foo:\n\tgcc -o foo foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), "foo:\n\t@+-gcc -o foo foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), "foo:\n\tgcc \\\n\t\t-o \\\n\t\tfoo foo.c\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&WHITESPACE_LEADING_COMMAND.to_string())
+    );
+}
+
+pub static INCONSISTENT_CONTINUATION_INDENT: &str = "INCONSISTENT_CONTINUATION_INDENT: indent command continuation lines consistently";
+
+/// continuation_indent_class names the style of leading whitespace
+/// on a command continuation line.
+fn continuation_indent_class(line: &str) -> &'static str {
+    match line.chars().next() {
+        Some('\t') => "tab",
+        Some(' ') => "space",
+        _ => "none",
+    }
+}
+
+/// check_inconsistent_continuation_indent reports INCONSISTENT_CONTINUATION_INDENT violations.
+///
+/// This check scans raw text,
+/// as parsing joins continuation lines into a single command string.
+fn check_inconsistent_continuation_indent(
+    metadata: &inspect::Metadata,
+    makefile: &str,
+) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut command_line: usize = 0;
+    let mut prev_continues: bool = false;
+    let mut indent_classes: HashSet<&'static str> = HashSet::new();
+
+    for (i, line) in makefile.lines().enumerate() {
+        if command_line > 0 {
+            indent_classes.insert(continuation_indent_class(line));
+
+            if !line.ends_with('\\') {
+                if indent_classes.len() > 1 {
+                    warnings.push(Warning {
+                        path: metadata.path.to_string(),
+                        line: command_line,
+                        offset: 0,
+                        message: INCONSISTENT_CONTINUATION_INDENT.to_string(),
+                    });
+                }
+
+                command_line = 0;
+                indent_classes.clear();
+            }
+        } else if !prev_continues && line.starts_with('\t') && line.ends_with('\\') {
+            command_line = 1 + i;
+        }
+
+        prev_continues = line.ends_with('\\');
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_inconsistent_continuation_indent() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\tgcc \\\n\t\t-o \\\n  foo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_CONTINUATION_INDENT.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\tgcc \\\n\t\t-o \\\nfoo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_CONTINUATION_INDENT.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\tgcc \\\n\t\t-o \\\n\t\tfoo foo.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&INCONSISTENT_CONTINUATION_INDENT.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:\n\tgcc -o foo foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&INCONSISTENT_CONTINUATION_INDENT.to_string()));
+}
+
+pub static MISSING_FINAL_EOL: &str =
+    "MISSING_FINAL_EOL: UNIX text files may process poorly without a final LF";
+
+/// check_final_eol reports MISSING_FINAL_EOL violations.
+fn check_final_eol(metadata: &inspect::Metadata, _: &[ast::Gem]) -> Vec<Warning> {
+    if !metadata.is_empty && !metadata.has_final_eol {
+        return vec![Warning {
+            path: metadata.path.to_string(),
+            line: metadata.lines,
+            offset: 0,
+            message: MISSING_FINAL_EOL.to_string(),
+        }];
+    }
+
+    Vec::new()
+}
+
+#[test]
+pub fn test_final_eol() {
+    let mf_pkg: &str = ".POSIX:\nPKG = curl";
+    let mut md_pkg: inspect::Metadata = mock_md("-");
+    md_pkg.is_empty = mf_pkg.is_empty();
+    md_pkg.has_final_eol = mf_pkg.chars().last().unwrap_or(' ') == '\n';
+
+    assert!(lint(&md_pkg, mf_pkg)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MISSING_FINAL_EOL.to_string()));
+
+    let mf_pkg_final_eol: &str = ".POSIX:\nPKG = curl\n";
+    let mut md_pkg_final_eol: inspect::Metadata = mock_md("-");
+    md_pkg_final_eol.is_empty = mf_pkg_final_eol.is_empty();
+    md_pkg_final_eol.has_final_eol = mf_pkg_final_eol.chars().last().unwrap_or(' ') == '\n';
+
+    assert!(!lint(&md_pkg_final_eol, mf_pkg_final_eol)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MISSING_FINAL_EOL.to_string()));
+
+    let mf_empty: &str = "";
+    let mut md_empty: inspect::Metadata = mock_md("-");
+    md_empty.is_empty = mf_empty.is_empty();
+    md_empty.has_final_eol = mf_empty.chars().last().unwrap_or(' ') == '\n';
+
+    assert!(!lint(&md_empty, mf_empty)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MISSING_FINAL_EOL.to_string()));
+}
+
+pub static SPACE_BEFORE_COLON: &str =
+    "SPACE_BEFORE_COLON: whitespace between target and colon parses inconsistently in older BSD makes";
+
+/// check_space_before_colon reports SPACE_BEFORE_COLON violations.
+///
+/// This pedantic, opt-in raw text check is not registered
+/// in the default check set. Enable it with [Linter::register_text],
+/// or with a [config::Config] enable list.
+pub fn check_space_before_colon(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for (i, line) in makefile.lines().enumerate() {
+        if line.starts_with('\t') || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let colon_index: usize = match line.find(':') {
+            None => continue,
+            Some(index) => index,
+        };
+
+        if let Some(equals_index) = line.find('=') {
+            if equals_index < colon_index {
+                continue;
+            }
+        }
+
+        if line[colon_index..].trim_start_matches(':').starts_with('=') {
+            continue;
+        }
+
+        if line[..colon_index].ends_with([' ', '\t']) {
+            warnings.push(Warning {
+                path: metadata.path.to_string(),
+                line: 1 + i,
+                offset: 0,
+                message: SPACE_BEFORE_COLON.to_string(),
+            });
+        }
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_space_before_colon() {
+    assert!(check_space_before_colon(
+        &mock_md("-"),
+        ".POSIX:\nfoo : foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SPACE_BEFORE_COLON.to_string()));
+
+    assert!(!check_space_before_colon(
+        &mock_md("-"),
+        ".POSIX:\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SPACE_BEFORE_COLON.to_string()));
+
+    assert!(!check_space_before_colon(&mock_md("-"), "PKG ::= curl\n")
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&SPACE_BEFORE_COLON.to_string()));
+}
+
+pub static UNSILENCED_ECHO: &str = "UNSILENCED_ECHO: prefix status commands like echo and printf with at (@), or declare .SILENT";
+
+/// check_unsilenced_echo reports UNSILENCED_ECHO violations.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+///
+/// Commands redirecting output to a file are skipped,
+/// as their output forms the build product rather than status noise.
+pub fn check_unsilenced_echo(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut has_global_silent: bool = false;
+    let mut marked_silent_targets: HashSet<&String> = HashSet::new();
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".SILENT".to_string()) {
+                if ps.is_empty() {
+                    has_global_silent = true;
+                }
+
+                for p in ps {
+                    marked_silent_targets.insert(p);
+                }
+            }
+        }
+    }
+
+    if has_global_silent {
+        return Vec::new();
+    }
+
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs } => {
+                ts.iter().all(|e2| !marked_silent_targets.contains(e2))
+                    && cs.iter().any(|e2| {
+                        let command: &str = e2.trim_start_matches(['-', '+']);
+                        !command.starts_with('@')
+                            && !command.contains('>')
+                            && ["echo", "printf"]
+                                .contains(&command.split_whitespace().next().unwrap_or(""))
+                    })
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: UNSILENCED_ECHO.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_unsilenced_echo() {
+    assert!(check_unsilenced_echo(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:\n\techo building...\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNSILENCED_ECHO.to_string()));
+
+    assert!(!check_unsilenced_echo(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:\n\t@echo building...\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNSILENCED_ECHO.to_string()));
+
+    assert!(!check_unsilenced_echo(
+        &mock_md("-"),
+        &ast::parse_posix(
+            "-",
+            ".POSIX:\n.SILENT: all\nall:\n\techo building...\n\tgcc -o foo foo.c\n"
+        )
+        .unwrap()
+        .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNSILENCED_ECHO.to_string()));
+
+    assert!(!check_unsilenced_echo(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nfoo.h:\n\techo \"#pragma once\" > foo.h\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNSILENCED_ECHO.to_string()));
+}
+
+pub static GLOBAL_NOTPARALLEL: &str =
+    "GLOBAL_NOTPARALLEL: .NOTPARALLEL disables parallelism globally; scope ordering with .WAIT markers or explicit prerequisites";
+
+/// check_global_notparallel reports GLOBAL_NOTPARALLEL violations.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_global_notparallel(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts,
+                cs: _,
+            } => ts.contains(&".NOTPARALLEL".to_string()),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: GLOBAL_NOTPARALLEL.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_global_notparallel() {
+    assert!(check_global_notparallel(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\n.NOTPARALLEL:\nall:;echo done\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&GLOBAL_NOTPARALLEL.to_string()));
+
+    assert!(!check_global_notparallel(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall: build .WAIT test\nbuild:;\ntest:;\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&GLOBAL_NOTPARALLEL.to_string()));
+}
+
+pub static SUFFIXES_CLEARED: &str =
+    "SUFFIXES_CLEARED: bare .SUFFIXES disables all built-in inference rules; confirm intent or list suffixes";
+
+/// check_suffixes_cleared reports SUFFIXES_CLEARED violations.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_suffixes_cleared(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts,
+                cs: _,
+            } => ts.contains(&".SUFFIXES".to_string()) && ps.is_empty(),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: SUFFIXES_CLEARED.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_suffixes_cleared() {
+    assert!(check_suffixes_cleared(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\n.SUFFIXES:\n.c.o:\n\t$(CC) -c $<\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SUFFIXES_CLEARED.to_string()));
+
+    assert!(!check_suffixes_cleared(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\n.SUFFIXES: .c .o\n.c.o:\n\t$(CC) -c $<\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SUFFIXES_CLEARED.to_string()));
+}
+
+pub static BOOLEAN_MACRO_DEFAULT: &str =
+    "BOOLEAN_MACRO_DEFAULT: make has no booleans and any non-empty value reads as set; prefer empty vs. non-empty conventions";
+
+/// check_boolean_macro_default reports BOOLEAN_MACRO_DEFAULT violations.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_boolean_macro_default(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n: _, op, v } => {
+                op == "?=" && matches!(v.trim().to_lowercase().as_str(), "true" | "false" | "yes" | "no")
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: BOOLEAN_MACRO_DEFAULT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_boolean_macro_default() {
+    assert!(check_boolean_macro_default(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nDEBUG ?= false\nall:;echo done\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BOOLEAN_MACRO_DEFAULT.to_string()));
+
+    assert!(!check_boolean_macro_default(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nDEBUG ?=\nall:;echo done\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BOOLEAN_MACRO_DEFAULT.to_string()));
+
+    assert!(!check_boolean_macro_default(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nDEBUG = true\nall:;echo done\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BOOLEAN_MACRO_DEFAULT.to_string()));
+}
+
+pub static UNUSED_PREREQUISITE: &str =
+    "UNUSED_PREREQUISITE: commands reference neither the prerequisites nor automatic macros; the dependency may be decorative or stale";
+
+/// check_unused_prerequisite reports UNUSED_PREREQUISITE violations.
+///
+/// This heuristic is prone to false positives,
+/// e.g. for rules delegating work to scripts.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_unused_prerequisite(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts,
+                cs,
+            } => {
+                !ts.iter().any(|e2| e2.starts_with('.'))
+                    && !ps.is_empty()
+                    && !cs.is_empty()
+                    && !cs.iter().any(|e2| {
+                        ["$<", "$?", "$^", "$*"].iter().any(|e3| e2.contains(e3))
+                            || ps.iter().any(|e3| e2.contains(e3.as_str()))
+                    })
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: UNUSED_PREREQUISITE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_unused_prerequisite() {
+    assert!(check_unused_prerequisite(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nfoo: a.c b.c\n\tgcc -o foo main.c\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNUSED_PREREQUISITE.to_string()));
+
+    assert!(!check_unused_prerequisite(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nfoo: foo.c\n\tgcc -o foo foo.c\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNUSED_PREREQUISITE.to_string()));
+
+    assert!(!check_unused_prerequisite(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nfoo: foo.c\n\tgcc -o foo $<\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNUSED_PREREQUISITE.to_string()));
+
+    assert!(!check_unused_prerequisite(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall: build test\nbuild:;\ntest:;\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNUSED_PREREQUISITE.to_string()));
+}
+
+pub static PHONY_SPLIT: &str =
+    "PHONY_SPLIT: .PHONY declarations scattered across the file; consolidate into a single line";
+
+pub static PHONY_CONSOLIDATED: &str =
+    "PHONY_CONSOLIDATED: consolidated .PHONY declaration; prefer per-target .PHONY lines adjacent to each rule";
+
+/// phony_gems collects .PHONY declarations.
+fn phony_gems(gems: &[ast::Gem]) -> Vec<&ast::Gem> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts,
+                cs: _,
+            } => ts.contains(&".PHONY".to_string()),
+            _ => false,
+        })
+        .collect()
+}
+
+/// check_phony_split reports PHONY_SPLIT violations.
+///
+/// This check enforces the consolidated .PHONY style.
+/// Mutually exclusive with [check_phony_consolidated].
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_phony_split(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let phonies: Vec<&ast::Gem> = phony_gems(gems);
+
+    if phonies.len() < 2 {
+        return Vec::new();
+    }
+
+    phonies
+        .into_iter()
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PHONY_SPLIT.to_string(),
+        })
+        .collect()
+}
+
+/// check_phony_consolidated reports PHONY_CONSOLIDATED violations.
+///
+/// This check enforces the per-target .PHONY style.
+/// Mutually exclusive with [check_phony_split].
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_phony_consolidated(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    phony_gems(gems)
+        .into_iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts: _,
+                cs: _,
+            } => ps.len() > 1,
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: PHONY_CONSOLIDATED.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_phony_split() {
+    assert!(check_phony_split(
+        &mock_md("-"),
+        &ast::parse_posix(
+            "-",
+            ".POSIX:\n.PHONY: build\nbuild:;\n.PHONY: test\ntest:;\n"
+        )
+        .unwrap()
+        .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_SPLIT.to_string()));
+
+    assert!(!check_phony_split(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\n.PHONY: build test\nbuild:;\ntest:;\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_SPLIT.to_string()));
+}
+
+#[test]
+pub fn test_phony_consolidated() {
+    assert!(check_phony_consolidated(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\n.PHONY: build test\nbuild:;\ntest:;\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_CONSOLIDATED.to_string()));
+
+    assert!(!check_phony_consolidated(
+        &mock_md("-"),
+        &ast::parse_posix(
+            "-",
+            ".POSIX:\n.PHONY: build\nbuild:;\n.PHONY: test\ntest:;\n"
+        )
+        .unwrap()
+        .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_CONSOLIDATED.to_string()));
+}
+
+pub static TODO_COMMENT: &str =
+    "TODO_COMMENT: comment contains a TODO, FIXME, or XXX debt marker";
+
+/// check_todo_comment reports TODO_COMMENT violations.
+///
+/// As a raw text scan, this check covers comments
+/// erased during parsing.
+///
+/// This informational, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register_text],
+/// or with a [config::Config] enable list.
+pub fn check_todo_comment(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    makefile
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| match line.find('#') {
+            Some(i) => {
+                let comment: &str = &line[i..];
+                ["TODO", "FIXME", "XXX"].iter().any(|e| comment.contains(e))
+            }
+            None => false,
+        })
+        .map(|(i, _)| Warning {
+            path: metadata.path.to_string(),
+            line: 1 + i,
+            offset: 0,
+            message: TODO_COMMENT.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_todo_comment() {
+    assert!(check_todo_comment(
+        &mock_md("-"),
+        ".POSIX:\n# TODO: cache artifacts\nall:;echo done\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&TODO_COMMENT.to_string()));
+
+    assert!(check_todo_comment(
+        &mock_md("-"),
+        ".POSIX:\nall:;echo done # FIXME flaky on NetBSD\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&TODO_COMMENT.to_string()));
+
+    assert!(!check_todo_comment(
+        &mock_md("-"),
+        ".POSIX:\n# build the default target\nall:;echo done\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&TODO_COMMENT.to_string()));
+}
+
+pub static SIMILAR_TARGET: &str =
+    "SIMILAR_TARGET: near-identical target names may hide a typo; rename or remove one of the pair";
+
+/// edit_distance computes the Damerau-Levenshtein distance
+/// (optimal string alignment) between two strings,
+/// counting adjacent transpositions as single edits.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let av: Vec<char> = a.chars().collect();
+    let bv: Vec<char> = b.chars().collect();
+    let mut d: Vec<Vec<usize>> = vec![vec![0; 1 + bv.len()]; 1 + av.len()];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=av.len() {
+        for j in 1..=bv.len() {
+            let cost: usize = usize::from(av[i - 1] != bv[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && av[i - 1] == bv[j - 2] && av[i - 2] == bv[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[av.len()][bv.len()]
+}
+
+/// check_similar_target_with reports SIMILAR_TARGET violations,
+/// flagging non-special target name pairs within the given edit distance.
+pub fn check_similar_target_with(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+    max_distance: usize,
+) -> Vec<Warning> {
+    let targets: Vec<(&String, &ast::Gem)> = gems
+        .iter()
+        .flat_map(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts,
+                cs: _,
+            } => ts
+                .iter()
+                .filter(|e2| !e2.starts_with('.'))
+                .map(|e2| (e2, e))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect();
+
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for (i, (name, gem)) in targets.iter().enumerate() {
+        for (name2, gem2) in &targets[1 + i..] {
+            let distance: usize = edit_distance(name, name2);
+
+            if distance == 0 || distance > max_distance {
+                continue;
+            }
+
+            for e in [gem, gem2] {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: e.l,
+                    offset: e.o,
+                    message: SIMILAR_TARGET.to_string(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+/// check_similar_target reports SIMILAR_TARGET violations
+/// within edit distance 1.
+///
+/// This fuzzy heuristic can false-positive on intentional
+/// target families like test-1 and test-2.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_similar_target(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_similar_target_with(metadata, gems, 1)
+}
+
+#[test]
+pub fn test_similar_target() {
+    assert!(check_similar_target(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nbuild:;cargo build\nbuidl: build\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMILAR_TARGET.to_string()));
+
+    assert!(!check_similar_target(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nbuild:;cargo build\ndeploy: build\n\tscp foo host:\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SIMILAR_TARGET.to_string()));
+
+    assert!(!check_similar_target_with(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nbuild:;cargo build\nbiudl: build\n")
+            .unwrap()
+            .ns,
+        2,
+    )
+    .is_empty());
+}
+
+pub static OUT_OF_TREE_WRITE: &str =
+    "OUT_OF_TREE_WRITE: commands writing outside the project tree harm reproducibility; route installs through $(DESTDIR) and $(PREFIX)";
+
+/// escapes_tree reports whether a destination path
+/// leaves the project directory tree.
+///
+/// Paths expanding macros are skipped, as their values are unknown,
+/// covering typical $(DESTDIR) and $(PREFIX) install patterns.
+fn escapes_tree(pth: &str) -> bool {
+    !pth.contains('$')
+        && !pth.starts_with("/dev/")
+        && (pth.starts_with('/') || pth.split('/').any(|e| e == ".."))
+}
+
+/// writes_out_of_tree reports whether a command redirects output,
+/// or copies files, to a destination outside the project tree.
+fn writes_out_of_tree(command: &str) -> bool {
+    for simple in strip_quoted_regions(command).split([';', '|', '&', '(', ')']) {
+        let tokens: Vec<&str> = simple.split_whitespace().collect();
+
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let command_word: &str = tokens[0].trim_start_matches(['@', '+', '-']);
+
+        if ["cp", "mv", "install"].contains(&command_word)
+            && tokens
+                .iter()
+                .skip(1)
+                .next_back()
+                .is_some_and(|e| !e.starts_with('-') && escapes_tree(e))
+        {
+            return true;
+        }
+
+        let mut expect_redirect_target: bool = false;
+
+        for token in &tokens {
+            if expect_redirect_target {
+                if escapes_tree(token) {
+                    return true;
+                }
+
+                expect_redirect_target = false;
+                continue;
+            }
+
+            let t: &str = token.trim_start_matches(['1', '2']);
+
+            if t == ">" || t == ">>" {
+                expect_redirect_target = true;
+            } else if let Some(dest) = t.strip_prefix(">>").or_else(|| t.strip_prefix('>')) {
+                if !dest.is_empty() && escapes_tree(dest) {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// check_out_of_tree_write reports OUT_OF_TREE_WRITE violations.
+///
+/// This heuristic is prone to false negatives,
+/// e.g. for writes hidden in scripts or macro expansions.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_out_of_tree_write(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts: _,
+                cs,
+            } => cs.iter().any(|e2| writes_out_of_tree(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: OUT_OF_TREE_WRITE.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_out_of_tree_write() {
+    assert!(check_out_of_tree_write(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\ninstall: foo\n\tcp foo /usr/local/bin/foo\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&OUT_OF_TREE_WRITE.to_string()));
+
+    assert!(check_out_of_tree_write(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:;./gen >../out.txt\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&OUT_OF_TREE_WRITE.to_string()));
+
+    assert!(!check_out_of_tree_write(
+        &mock_md("-"),
+        &ast::parse_posix(
+            "-",
+            ".POSIX:\ninstall: foo\n\tinstall -m 755 foo $(DESTDIR)$(PREFIX)/bin/foo\n"
+        )
+        .unwrap()
+        .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&OUT_OF_TREE_WRITE.to_string()));
+
+    assert!(!check_out_of_tree_write(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:;./gen >out.txt 2>/dev/null\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&OUT_OF_TREE_WRITE.to_string()));
+}
+
+pub static EXCESS_BLANK_LINE: &str =
+    "EXCESS_BLANK_LINE: leading blank lines, trailing blank lines, and runs of three or more blank lines add noise";
+
+/// check_excess_blank_line reports EXCESS_BLANK_LINE violations,
+/// one per offending blank run, at the run's first line.
+///
+/// This stylistic, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register_text],
+/// or with a [config::Config] enable list.
+pub fn check_excess_blank_line(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let lines: Vec<&str> = makefile.lines().collect();
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut run_start: Option<usize> = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.trim().is_empty() {
+            run_start.get_or_insert(i);
+            continue;
+        }
+
+        if let Some(start) = run_start.take() {
+            if start == 0 || i - start > 2 {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: 1 + start,
+                    offset: 0,
+                    message: EXCESS_BLANK_LINE.to_string(),
+                });
+            }
+        }
+    }
+
+    if let Some(start) = run_start {
+        warnings.push(Warning {
+            path: metadata.path.to_string(),
+            line: 1 + start,
+            offset: 0,
+            message: EXCESS_BLANK_LINE.to_string(),
+        });
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_excess_blank_line() {
+    assert!(check_excess_blank_line(&mock_md("-"), "\n.POSIX:\nall:;echo done\n")
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EXCESS_BLANK_LINE.to_string()));
+
+    assert!(check_excess_blank_line(&mock_md("-"), ".POSIX:\nall:;echo done\n\n\n")
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EXCESS_BLANK_LINE.to_string()));
+
+    assert!(check_excess_blank_line(
+        &mock_md("-"),
+        ".POSIX:\n\n\n\nall:;echo done\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&EXCESS_BLANK_LINE.to_string()));
+
+    assert!(check_excess_blank_line(&mock_md("-"), ".POSIX:\n\nall:;echo done\n").is_empty());
+}
+
+pub static BACKTICK_SUBSTITUTION: &str =
+    "BACKTICK_SUBSTITUTION: backtick command substitution is hard to nest; prefer $$(...) shell substitution";
+
+/// has_unquoted_backtick reports whether a command contains
+/// a backtick outside single quotes.
+///
+/// Backticks inside double quotes remain active in sh,
+/// so only single quotes render them literal.
+fn has_unquoted_backtick(command: &str) -> bool {
+    let mut in_single_quotes: bool = false;
+
+    for c in command.chars() {
+        match c {
+            '\'' => in_single_quotes = !in_single_quotes,
+            '`' if !in_single_quotes => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// check_backtick_substitution reports BACKTICK_SUBSTITUTION violations.
+///
+/// Note that shell dollar signs in commands escape as double
+/// dollar signs, e.g. $$(git rev-parse HEAD), lest make expand
+/// them as make macros.
+///
+/// This stylistic, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_backtick_substitution(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts: _,
+                cs,
+            } => cs.iter().any(|e2| has_unquoted_backtick(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: BACKTICK_SUBSTITUTION.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_backtick_substitution() {
+    assert!(check_backtick_substitution(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:;echo `git rev-parse HEAD`\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BACKTICK_SUBSTITUTION.to_string()));
+
+    assert!(!check_backtick_substitution(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:;echo $$(git rev-parse HEAD)\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BACKTICK_SUBSTITUTION.to_string()));
+
+    assert!(!check_backtick_substitution(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:;echo 'literal ` backtick'\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&BACKTICK_SUBSTITUTION.to_string()));
+}
+
+lazy_static::lazy_static! {
+    /// BUILD_TOOL_MACROS collects macro names conventionally
+    /// overridden by packagers and integrators.
+    pub static ref BUILD_TOOL_MACROS: HashSet<&'static str> = vec![
+        "AR",
+        "ARFLAGS",
+        "CC",
+        "CFLAGS",
+        "CPPFLAGS",
+        "CXX",
+        "CXXFLAGS",
+        "FC",
+        "FFLAGS",
+        "LDFLAGS",
+        "LDLIBS",
+        "LEX",
+        "LFLAGS",
+        "YACC",
+        "YFLAGS",
+    ]
+    .into_iter()
+    .collect::<HashSet<&'static str>>();
+}
+
+pub static FORCED_BUILD_MACRO: &str =
+    "FORCED_BUILD_MACRO: = blocks packager overrides of build tool macros; prefer ?=";
+
+/// check_forced_build_macro reports FORCED_BUILD_MACRO violations.
+///
+/// Only build tool macros referenced in rule commands are flagged,
+/// as unreferenced assignments do not affect packager builds.
+///
+/// This opinionated, opt-in check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_forced_build_macro(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for gem in gems {
+        if let ast::Ore::Mc { n, op, v: _ } = &gem.n {
+            if !BUILD_TOOL_MACROS.contains(n.as_str())
+                || !matches!(op.as_str(), "=" | ":=" | "::=" | ":::=" | "!=")
+            {
+                continue;
+            }
+
+            let paren_reference: String = format!("$({})", n);
+            let curly_reference: String = format!("${{{}}}", n);
+
+            let referenced: bool = gems.iter().any(|e| match &e.n {
+                ast::Ore::Ru {
+                    dc: _,
+                    os: _,
+                    ps: _,
+                    ts: _,
+                    cs,
+                } => cs
+                    .iter()
+                    .any(|e2| e2.contains(&paren_reference) || e2.contains(&curly_reference)),
+                _ => false,
+            });
+
+            if referenced {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: gem.l,
+                    offset: gem.o,
+                    message: FORCED_BUILD_MACRO.to_string(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_forced_build_macro() {
+    assert!(check_forced_build_macro(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nCC = gcc\nall:;$(CC) -o all all.c\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&FORCED_BUILD_MACRO.to_string()));
+
+    assert!(!check_forced_build_macro(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nCC ?= gcc\nall:;$(CC) -o all all.c\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&FORCED_BUILD_MACRO.to_string()));
+
+    assert!(!check_forced_build_macro(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nCC = gcc\nall:;echo done\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&FORCED_BUILD_MACRO.to_string()));
+
+    assert!(!check_forced_build_macro(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nAPP = hello\nall:;echo $(APP)\n")
+            .unwrap()
+            .ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&FORCED_BUILD_MACRO.to_string()));
+}
+
+pub static TAB_FIELD_SEPARATOR: &str =
+    "TAB_FIELD_SEPARATOR: separate targets and prerequisites with single spaces, not tabs";
+
+/// check_tab_field_separator reports TAB_FIELD_SEPARATOR violations.
+fn check_tab_field_separator(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for (i, line) in makefile.lines().enumerate() {
+        if line.starts_with('\t') || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let colon_index: usize = match line.find(':') {
+            None => continue,
+            Some(index) => index,
+        };
+
+        if let Some(equals_index) = line.find('=') {
+            if equals_index < colon_index {
+                continue;
+            }
+        }
+
+        if line[colon_index..].trim_start_matches(':').starts_with('=') {
+            continue;
+        }
+
+        let targets: &str = &line[..colon_index];
+        let prerequisites: &str = line[colon_index..]
+            .split(';')
+            .next()
+            .unwrap_or("")
+            .split('#')
+            .next()
+            .unwrap_or("");
+
+        if targets.contains('\t') || prerequisites.contains('\t') {
+            warnings.push(Warning {
+                path: metadata.path.to_string(),
+                line: 1 + i,
+                offset: 0,
+                message: TAB_FIELD_SEPARATOR.to_string(),
+            });
+        }
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_tab_field_separator() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: a.c\tb.c\n\tgcc -o foo a.c b.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&TAB_FIELD_SEPARATOR.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo\tbar: a.c\n\ttouch foo bar\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&TAB_FIELD_SEPARATOR.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nfoo: a.c b.c\n\tgcc -o foo a.c b.c\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&TAB_FIELD_SEPARATOR.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG =\tcurl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&TAB_FIELD_SEPARATOR.to_string()));
+}
 
-    assert!(!lint(&mock_md("foo.Makefile"), ".POSIX:\nPKG=curl\n")
+pub static ORPHAN_COMMAND: &str =
+    "ORPHAN_COMMAND: command commences before first target; attach tab indented lines to a rule";
+
+/// is_rule_line estimates whether a raw makefile line
+/// declares a rule, as opposed to a macro definition,
+/// include line, or other construct.
+fn is_rule_line(line: &str) -> bool {
+    let colon_index: usize = match line.find(':') {
+        None => return false,
+        Some(index) => index,
+    };
+
+    if let Some(equals_index) = line.find('=') {
+        if equals_index < colon_index {
+            return false;
+        }
+    }
+
+    !line[colon_index..].trim_start_matches(':').starts_with('=')
+}
+
+/// check_orphan_command reports ORPHAN_COMMAND violations.
+///
+/// This check scans raw text,
+/// as the grammar ties tab indented commands to rules,
+/// enriching the resulting parse errors with actionable guidance.
+fn check_orphan_command(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut in_recipe: bool = false;
+    let mut prev_continues: bool = false;
+
+    for (i, line) in makefile.lines().enumerate() {
+        if prev_continues {
+            prev_continues = line.ends_with('\\');
+            continue;
+        }
+
+        prev_continues = line.ends_with('\\');
+
+        if line.starts_with('\t') {
+            if !in_recipe {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: 1 + i,
+                    offset: 0,
+                    message: ORPHAN_COMMAND.to_string(),
+                });
+            }
+
+            continue;
+        }
+
+        let trimmed: &str = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        in_recipe = is_rule_line(line);
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_orphan_command() {
+    assert!(check_orphan_command(&mock_md("-"), "\techo orphan\n.POSIX:\nall:;\n")
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&ORPHAN_COMMAND.to_string()));
+
+    assert!(
+        check_orphan_command(&mock_md("-"), ".POSIX:\nPKG = curl\n\techo orphan\n")
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&ORPHAN_COMMAND.to_string())
+    );
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:\n\techo done\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+        .contains(&ORPHAN_COMMAND.to_string()));
 
-    assert!(!lint(&mock_md("foo.makefile"), ".POSIX:\nPKG=curl\n")
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl \\\n\tlibcurl\nall:;\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&MAKEFILE_PRECEDENCE.to_string()));
+        .contains(&ORPHAN_COMMAND.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\techo one\n\n# note\n\techo two\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&ORPHAN_COMMAND.to_string()));
 }
 
-pub static CURDIR_ASSIGNMENT_NOP: &str =
-    "CURDIR_ASSIGNMENT_NOP: CURDIR assignment does not change the make working directory";
+pub static EMPTY_INLINE_COMMAND: &str =
+    "EMPTY_INLINE_COMMAND: trailing semicolon declares an empty inline command; remove the semicolon";
 
-/// check_curdir_assignment_nop reports CURDIR_ASSIGNMENT_NOP violations.
-fn check_curdir_assignment_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Mc { n, v: _ } => n == "CURDIR",
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: CURDIR_ASSIGNMENT_NOP.to_string(),
-        })
-        .collect()
+/// check_empty_inline_command reports EMPTY_INLINE_COMMAND violations.
+///
+/// As a raw text scan, this check covers empty inline commands
+/// erased during parsing.
+///
+/// Prerequisite-free no-op declarations like "foo:;" are skipped,
+/// as the semicolon there deliberately stands in for a command.
+fn check_empty_inline_command(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut prev_continues: bool = false;
+
+    for (i, line) in makefile.lines().enumerate() {
+        let continues: bool = line.ends_with('\\');
+
+        if prev_continues || line.starts_with('\t') || line.trim_start().starts_with('#') {
+            prev_continues = continues;
+            continue;
+        }
+
+        prev_continues = continues;
+        let trimmed: &str = line.trim_end();
+
+        if !trimmed.ends_with(';') || trimmed.find(';') != Some(trimmed.len() - 1) {
+            continue;
+        }
+
+        let head: &str = &trimmed[..trimmed.len() - 1];
+
+        if let Some(colon) = head.find(':') {
+            if !head[colon..].trim_start_matches(':').trim().is_empty() {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: 1 + i,
+                    offset: 0,
+                    message: EMPTY_INLINE_COMMAND.to_string(),
+                });
+            }
+        }
+    }
+
+    warnings
 }
 
 #[test]
-pub fn test_curdir_assignment_nop() {
-    assert!(lint(&mock_md("-"), ".POSIX:\nCURDIR = build\n")
+pub fn test_empty_inline_command() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nall: build;\nbuild:;echo ok\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&CURDIR_ASSIGNMENT_NOP.to_string()));
+        .contains(&EMPTY_INLINE_COMMAND.to_string()));
 
-    assert!(!lint(&mock_md("-"), ".POSIX:\n")
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall: build\nbuild:;echo ok\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&CURDIR_ASSIGNMENT_NOP.to_string()));
+        .contains(&EMPTY_INLINE_COMMAND.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EMPTY_INLINE_COMMAND.to_string()));
 }
 
-pub static WD_NOP: &str =
-    "WD_NOP: change directory commands may not persist across successive commands or rules";
+pub static UNESCAPED_HASH: &str =
+    "UNESCAPED_HASH: hash begins a comment mid-value, truncating the macro definition; escape literal hashes";
+
+/// check_unescaped_hash reports UNESCAPED_HASH violations.
+///
+/// As a raw text scan, this check covers comment content
+/// erased during parsing.
+fn check_unescaped_hash(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut prev_continues: bool = false;
+
+    for (i, line) in makefile.lines().enumerate() {
+        let continues: bool = line.ends_with('\\');
+
+        if prev_continues || line.starts_with('\t') || line.trim_start().starts_with('#') {
+            prev_continues = continues;
+            continue;
+        }
+
+        prev_continues = continues;
+
+        if let Some(eq) = line.find('=') {
+            let name: &str = line[..eq].trim_end_matches(['+', '?', ':', '!']).trim();
+            let value: &str = &line[1 + eq..];
+
+            if !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+                && value
+                    .char_indices()
+                    .any(|(j, c)| c == '#' && (j == 0 || value.as_bytes()[j - 1] != b'\\'))
+            {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: 1 + i,
+                    offset: 0,
+                    message: UNESCAPED_HASH.to_string(),
+                });
+            }
+        }
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_unescaped_hash() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nCFLAGS = -DX=a#b\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNESCAPED_HASH.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\nPKG = curl # the download tool\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNESCAPED_HASH.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNESCAPED_HASH.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\n# PKG = curl\nall:\n\techo '#'\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNESCAPED_HASH.to_string()));
+}
+
+pub static PHONY_TARGET: &str = "PHONY_TARGET: mark common artifactless rules as .PHONY";
+
+/// check_phony_target reports PHONY_TARGET violations.
+fn check_phony_target(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut marked_phony_targets: HashSet<&String> = HashSet::new();
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".PHONY".to_string()) {
+                for p in ps {
+                    marked_phony_targets.insert(p);
+                }
+            }
+        }
+    }
 
-/// check_wd_nop reports WD_NOP violations.
-fn check_wd_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts: _, cs } => cs
-                .iter()
-                .any(|e2| WD_COMMANDS.contains(&e2.split_whitespace().next().unwrap_or(""))),
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ }
+                if !ts.iter().any(|e2| ast::SPECIAL_TARGETS.contains(e2))
+                    && ts.iter().any(|e2| !marked_phony_targets.contains(e2)) =>
+            {
+                ts.iter().any(|e2| {
+                    LOWER_CONVENTIONAL_PHONY_TARGETS_PATTERN.is_match(e2.to_lowercase().as_str())
+                })
+            }
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: WD_NOP.to_string(),
+            offset: e.o,
+            message: PHONY_TARGET.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_wd_nop() {
+pub fn test_phony_target() {
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nall:\n\techo \"Hello World!\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&PHONY_TARGET.to_string())
+    );
+
     assert!(lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: all\nall:\n\tcd foo\n\n\tpushd bar\n\tpopd\n"
+        ".POSIX:\nlint:;\ninstall:;\nuninstall:;\npublish:;\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&WD_NOP.to_string()));
+    .contains(&PHONY_TARGET.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: all\nall:\n\ttar -C foo czvf foo.tgz .\n"
+        ".POSIX:\n.PHONY: all\nall:\n\techo \"Hello World!\"\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&WD_NOP.to_string()));
-}
-
-pub static WAIT_NOP: &str = "WAIT_NOP: .WAIT as a target has no effect";
-
-/// check_makefile_precedence reports WAIT_NOP violations.
-fn check_wait_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs: _ } => ts.contains(&".WAIT".to_string()),
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: WAIT_NOP.to_string(),
-        })
-        .collect()
-}
+    .contains(&PHONY_TARGET.to_string()));
 
-#[test]
-pub fn test_wait_nop() {
-    assert!(lint(&mock_md("-"), ".POSIX:\n.WAIT:\n")
+    assert!(
+        lint(
+            &mock_md("-"),
+            ".POSIX:\ntest: test-1 test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n"
+        )
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&WAIT_NOP.to_string()));
+        .contains(&PHONY_TARGET.to_string()));
 
     assert!(
-        !lint(&mock_md("-"), ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&WAIT_NOP.to_string()));
-}
+        !lint(
+            &mock_md("-"),
+            ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n"
+        )
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PHONY_TARGET.to_string()));
 
-pub static PHONY_NOP: &str = "PHONY_NOP: empty .PHONY has no effect";
+    assert!(
+        !lint(
+            &mock_md("-"),
+            ".POSIX:\n.PHONY: test\ntest: test-1 test-2\n.PHONY: test-1\ntest-1:\n\techo \"Hello World!\"\n.PHONY: test-2\ntest-2:\n\techo \"Hi World!\"\n"
+        )
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PHONY_TARGET.to_string()));
 
-/// check_phony_nop reports PHONY_NOP violations.
-fn check_phony_nop(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Ru { ps, ts, cs: _ } => ts.contains(&".PHONY".to_string()) && ps.is_empty(),
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: PHONY_NOP.to_string(),
-        })
-        .collect()
-}
+    assert!(lint(&mock_md("-"), ".POSIX:\nclean:\n\t-rm -rf bin\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PHONY_TARGET.to_string()));
 
-#[test]
-pub fn test_phony_nop() {
-    assert!(lint(
+    assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY:\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&PHONY_NOP.to_string()));
+    .contains(&PHONY_TARGET.to_string()));
+
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\nport: cross-compile archive\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&PHONY_TARGET.to_string())
+    );
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: test\ntest:\n\techo \"Hello World!\"\n"
+        ".POSIX:\n.PHONY: port\nport: cross-compile archive\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&PHONY_NOP.to_string()));
+    .contains(&PHONY_TARGET.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nempty:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PHONY_TARGET.to_string()));
+
+    // Ensure that absent targets do not trigger a PHONY_TARGET warning,
+    // unlike some makefile linters in the past.
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&PHONY_TARGET.to_string()));
 }
 
-pub static REDUNDANT_NOTPARALLEL_WAIT: &str =
-    "REDUNDANT_NOTPARALLEL_WAIT: .NOTPARALLEL with .WAIT is redundant and superfluous";
+lazy_static::lazy_static! {
+    /// ATTRIBUTE_SPECIAL_TARGETS collects special targets
+    /// whose prerequisites reference other targets by name.
+    pub static ref ATTRIBUTE_SPECIAL_TARGETS: Vec<&'static str> = vec![
+        ".IGNORE",
+        ".PRECIOUS",
+        ".SILENT",
+    ];
+}
 
-/// check_redundant_notparallel_wait reports REDUNDANT_NOTPARALLEL_WAIT violations.
-fn check_redundant_notparallel_wait(
+pub static DANGLING_SPECIAL_PREREQUISITE: &str = "DANGLING_SPECIAL_PREREQUISITE: .SILENT, .IGNORE, and .PRECIOUS prerequisites should reference declared targets";
+
+/// check_dangling_special_prerequisite reports DANGLING_SPECIAL_PREREQUISITE violations.
+///
+/// Include files are skipped,
+/// as they may reference targets declared in including makefiles.
+fn check_dangling_special_prerequisite(
     metadata: &inspect::Metadata,
     gems: &[ast::Gem],
 ) -> Vec<Warning> {
-    let has_notparallel: bool = gems.iter().any(|e| match &e.n {
-        ast::Ore::Ru { ps: _, ts, cs: _ } => ts.contains(&".NOTPARALLEL".to_string()),
-        _ => false,
-    });
-
-    if !has_notparallel {
+    if metadata.is_include_file {
         return Vec::new();
     }
 
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Ru { ps, ts: _, cs: _ } => ps.contains(&".WAIT".to_string()),
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: REDUNDANT_NOTPARALLEL_WAIT.to_string(),
-        })
-        .collect()
-}
-
-#[test]
-pub fn test_redundant_nonparallel_wait() {
-    assert!(
-        lint(&mock_md("-"), ".POSIX:\n.NOTPARALLEL:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&REDUNDANT_NOTPARALLEL_WAIT.to_string()));
-
-    assert!(
-        !lint(&mock_md("-"), ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&REDUNDANT_NOTPARALLEL_WAIT.to_string()));
-
-    assert!(
-        !lint(&mock_md("-"), ".POSIX:\n.NOTPARALLEL:\n.PHONY: test test-1 test-2\ntest: test-1 test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&REDUNDANT_NOTPARALLEL_WAIT.to_string()));
-}
-
-pub static REDUNDANT_SILENT_AT: &str =
-    "REDUNDANT_SILENT_AT: .SILENT with @ is redundant and superfluous";
-
-/// check_redundant_silent_at reports REDUNDANT_SILENT_AT violations.
-fn check_redundant_silent_at(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    let mut has_global_silence: bool = false;
-    let mut marked_silent_targets: HashSet<&String> = HashSet::new();
-
+    let mut declared_targets: HashSet<&String> = HashSet::new();
     for gem in gems {
-        if let ast::Ore::Ru { ps, ts, cs: _ } = &gem.n {
-            if ts.contains(&".SILENT".to_string()) {
-                if ps.is_empty() {
-                    has_global_silence = true;
-                }
-
-                for p in ps {
-                    marked_silent_targets.insert(p);
-                }
+        if let ast::Ore::Ru {
+            dc: _,
+            os: _,
+            ps: _,
+            ts,
+            cs: _,
+        } = &gem.n
+        {
+            for t in ts {
+                declared_targets.insert(t);
             }
         }
     }
 
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs } => {
-                cs.iter().any(|e2| e2.starts_with('@'))
-                    && (has_global_silence
-                        || ts.iter().any(|e2| marked_silent_targets.contains(e2)))
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts,
+                cs: _,
+            } => {
+                ts.iter()
+                    .any(|e2| ATTRIBUTE_SPECIAL_TARGETS.contains(&e2.as_str()))
+                    && ps.iter().any(|e2| {
+                        e2 != ".WAIT" && !e2.contains('$') && !declared_targets.contains(e2)
+                    })
             }
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: REDUNDANT_SILENT_AT.to_string(),
+            offset: e.o,
+            message: DANGLING_SPECIAL_PREREQUISITE.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_redundant_silent_at() {
+pub fn test_dangling_special_prerequisite() {
     assert!(lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: lint\n.SILENT:\nlint:\n\t@unmake .\n"
+        ".POSIX:\n.SILENT: build test\nbuild:\n\tgcc -o foo foo.c\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REDUNDANT_SILENT_AT.to_string()));
+    .contains(&DANGLING_SPECIAL_PREREQUISITE.to_string()));
 
-    assert!(lint(
+    assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: lint\n.SILENT: lint\nlint:\n\t@unmake .\n"
+        ".POSIX:\n.SILENT: build\nbuild:\n\tgcc -o foo foo.c\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REDUNDANT_SILENT_AT.to_string()));
+    .contains(&DANGLING_SPECIAL_PREREQUISITE.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: lint\n.SILENT: lint\nlint:\n\tunmake .\n"
+        ".POSIX:\n.IGNORE: $(TARGETS)\nbuild:\n\tgcc -o foo foo.c\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REDUNDANT_SILENT_AT.to_string()));
+    .contains(&DANGLING_SPECIAL_PREREQUISITE.to_string()));
 
-    assert!(
-        !lint(&mock_md("-"), ".POSIX:\n.PHONY: lint\nlint:\n\t@unmake .\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&REDUNDANT_SILENT_AT.to_string())
-    );
+    let mut md_include: inspect::Metadata = mock_md("provision.include.mk");
+    md_include.is_include_file = true;
+
+    assert!(!lint(&md_include, ".SILENT: build\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&DANGLING_SPECIAL_PREREQUISITE.to_string()));
 }
 
-pub static REDUNDANT_IGNORE_MINUS: &str =
-    "REDUNDANT_IGNORE_MINUS: .IGNORE with - is redundant and superfluous";
+pub static NO_OP_RULE: &str = "NO_OP_RULE: rule has no prerequisites and no commands";
 
-/// check_redundant_ignore_minus reports REDUNDANT_IGNORE_MINUS violations.
-fn check_redundant_ignore_minus(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    let mut marked_ignored_targets: HashSet<&String> = HashSet::new();
+/// check_no_op_rule reports NO_OP_RULE violations.
+fn check_no_op_rule(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut marked_phony_targets: HashSet<&String> = HashSet::new();
     for gem in gems {
-        if let ast::Ore::Ru { ps, ts, cs: _ } = &gem.n {
-            if ts.contains(&".IGNORE".to_string()) {
+        if let ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } = &gem.n {
+            if ts.contains(&".PHONY".to_string()) {
                 for p in ps {
-                    marked_ignored_targets.insert(p);
+                    marked_phony_targets.insert(p);
                 }
             }
         }
@@ -679,854 +7480,1072 @@ fn check_redundant_ignore_minus(metadata: &inspect::Metadata, gems: &[ast::Gem])
 
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs } => {
-                cs.iter().any(|e2| e2.starts_with('-'))
-                    && ts.iter().any(|e2| marked_ignored_targets.contains(e2))
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs } => {
+                ps.is_empty()
+                    && cs.is_empty()
+                    && !ts.iter().any(|e2| ast::SPECIAL_TARGETS.contains(e2))
+                    && ts.iter().any(|e2| !marked_phony_targets.contains(e2))
             }
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: REDUNDANT_IGNORE_MINUS.to_string(),
+            offset: e.o,
+            message: NO_OP_RULE.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_redundant_ignore_minus() {
-    assert!(lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: clean\n.IGNORE: clean\nclean:\n\t-rm -rf bin\n"
-    )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&REDUNDANT_IGNORE_MINUS.to_string()));
+pub fn test_no_op_rule() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nfoo:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NO_OP_RULE.to_string()));
 
-    assert!(!lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin\n"
-    )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&REDUNDANT_IGNORE_MINUS.to_string()));
+    assert!(!lint(&mock_md("-"), ".POSIX:\n.PHONY: foo\nfoo:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NO_OP_RULE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NO_OP_RULE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nfoo:\n\ttouch foo\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NO_OP_RULE.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\n.NOTPARALLEL:\nall:\n\techo \"Hello World!\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&NO_OP_RULE.to_string()));
 }
 
-pub static GLOBAL_IGNORE: &str =
-    "GLOBAL_IGNORE: .IGNORE without prerequisites may corrupt artifacts";
+pub static ABSOLUTE_INCLUDE: &str =
+    "ABSOLUTE_INCLUDE: absolute include paths vary across machines; prefer relative paths";
 
-/// check_global_ignore reports GLOBAL_IGNORE violations.
-fn check_global_ignore(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+/// is_absolute_include_path reports whether an include path
+/// is anchored to a filesystem root.
+fn is_absolute_include_path(s: &str) -> bool {
+    s.starts_with('/') || (s.len() > 1 && s.as_bytes()[1] == b':' && s.as_bytes()[0].is_ascii_alphabetic())
+}
+
+/// check_absolute_include reports ABSOLUTE_INCLUDE violations.
+///
+/// Paths expanding macros are skipped, as their values are unknown.
+fn check_absolute_include(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps, ts, cs: _ } => ts.contains(&".IGNORE".to_string()) && ps.is_empty(),
+            ast::Ore::In { ps } => ps
+                .iter()
+                .any(|e2| !e2.contains('$') && is_absolute_include_path(e2)),
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: GLOBAL_IGNORE.to_string(),
+            offset: e.o,
+            message: ABSOLUTE_INCLUDE.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_global_ignore() {
-    assert!(lint(&mock_md("-"), ".POSIX:\n.IGNORE:\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&GLOBAL_IGNORE.to_string()));
+pub fn test_absolute_include() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\ninclude /etc/make/common.mk\nall:;echo done\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&ABSOLUTE_INCLUDE.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: clean\n.IGNORE: clean\nclean:\n\trm -rf bin"
+        ".POSIX:\ninclude sys/common.mk\nall:;echo done\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&GLOBAL_IGNORE.to_string()));
+    .contains(&ABSOLUTE_INCLUDE.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin"
+        ".POSIX:\ninclude $(ROOT)/common.mk\nall:;echo done\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&GLOBAL_IGNORE.to_string()));
+    .contains(&ABSOLUTE_INCLUDE.to_string()));
 }
 
-pub static SIMPLIFY_AT: &str =
-    "SIMPLIFY_AT: replace individual at (@) signs with .SILENT target declaration(s)";
+pub static LATE_INCLUDE: &str =
+    "LATE_INCLUDE: group include lines near the top, before the first non-special rule";
 
-/// check_simplify_at reports SIMPLIFY_AT violations.
-fn check_simplify_at(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    let mut has_global_silence: bool = false;
-    let mut marked_silent_targets: HashSet<&String> = HashSet::new();
+/// check_late_include reports LATE_INCLUDE violations.
+fn check_late_include(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut found_nonspecial_rule: bool = false;
+    let mut warnings: Vec<Warning> = Vec::new();
 
     for gem in gems {
-        if let ast::Ore::Ru { ps, ts, cs: _ } = &gem.n {
-            if ts.contains(&".SILENT".to_string()) {
-                if ps.is_empty() {
-                    has_global_silence = true;
-                }
-
-                for p in ps {
-                    marked_silent_targets.insert(p);
-                }
+        match &gem.n {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ }
+                if ts.iter().any(|e| !ast::SPECIAL_TARGETS.contains(e)) =>
+            {
+                found_nonspecial_rule = true;
+            }
+            ast::Ore::In { ps: _ } if found_nonspecial_rule => {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: gem.l,
+                    offset: gem.o,
+                    message: LATE_INCLUDE.to_string(),
+                });
             }
+            _ => {}
         }
     }
 
-    if has_global_silence {
-        return Vec::new();
-    }
+    warnings
+}
+
+#[test]
+pub fn test_late_include() {
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\techo \"Hello World!\"\ninclude foo.mk\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LATE_INCLUDE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\ninclude foo.mk\nall:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LATE_INCLUDE.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.PHONY: all\ninclude foo.mk\nall:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&LATE_INCLUDE.to_string()));
+}
+
+pub static SUFFIXES_FRAGMENTATION: &str =
+    "SUFFIXES_FRAGMENTATION: consolidate .SUFFIXES clearing and additions into a single rule";
 
-    gems.iter()
+/// check_suffixes_fragmentation reports SUFFIXES_FRAGMENTATION violations.
+fn check_suffixes_fragmentation(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let suffixes_gems: Vec<&ast::Gem> = gems
+        .iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs } => {
-                cs.len() > 1
-                    && cs.iter().all(|e2| e2.starts_with('@'))
-                    && !ts.iter().any(|e2| marked_silent_targets.contains(e2))
-            }
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => ts.contains(&".SUFFIXES".to_string()),
             _ => false,
         })
+        .collect();
+
+    let has_clearing_rule: bool = suffixes_gems.iter().any(|e| match &e.n {
+        ast::Ore::Ru { dc: _, os: _, ps, ts: _, cs: _ } => ps.is_empty(),
+        _ => false,
+    });
+
+    if suffixes_gems.len() < 2 || !has_clearing_rule {
+        return Vec::new();
+    }
+
+    suffixes_gems
+        .into_iter()
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: SIMPLIFY_AT.to_string(),
+            offset: e.o,
+            message: SUFFIXES_FRAGMENTATION.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_simplify_at() {
+pub fn test_suffixes_fragmentation() {
     assert!(lint(
         &mock_md("-"),
-        ".POSIX:\nwelcome:\n\t@echo foo\n\t@echo bar\n\t@echo baz\n"
+        ".POSIX:\n.SUFFIXES:\n.SUFFIXES: .c .o\n\n.c.o:\n\t$(CC) -c $<\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&SIMPLIFY_AT.to_string()));
+    .contains(&SUFFIXES_FRAGMENTATION.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\nwelcome:\n\t@echo foo\n\t@echo bar\n\techo baz\n"
+        ".POSIX:\n.SUFFIXES: .c .o\n\n.c.o:\n\t$(CC) -c $<\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&SIMPLIFY_AT.to_string()));
+    .contains(&SUFFIXES_FRAGMENTATION.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\n.SILENT: welcome\nwelcome:\n\techo foo\n\techo bar\n\techo baz\n"
+        ".POSIX:\n.SUFFIXES:\n\nall:\n\techo \"Hello World!\"\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&SIMPLIFY_AT.to_string()));
-}
+    .contains(&SUFFIXES_FRAGMENTATION.to_string()));
 
-pub static SIMPLIFY_MINUS: &str =
-    "SIMPLIFY_MINUS: replace individual hyphen-minus (-) signs with .IGNORE target declaration(s)";
-
-/// check_simplify_minus reports SIMPLIFY_MINUS violations.
-fn check_simplify_minus(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    let mut has_global_ignore: bool = false;
-    let mut marked_ignored_targets: HashSet<&String> = HashSet::new();
-
-    for gem in gems {
-        if let ast::Ore::Ru { ps, ts, cs: _ } = &gem.n {
-            if ts.contains(&".IGNORE".to_string()) {
-                if ps.is_empty() {
-                    has_global_ignore = true;
-                }
-
-                for p in ps {
-                    marked_ignored_targets.insert(p);
-                }
-            }
-        }
-    }
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n.SUFFIXES: .c .o\n.SUFFIXES: .cpp\n\n.c.o:\n\t$(CC) -c $<\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SUFFIXES_FRAGMENTATION.to_string()));
+}
 
-    if has_global_ignore {
-        return Vec::new();
-    }
+pub static DUPLICATE_PREREQUISITE: &str =
+    "DUPLICATE_PREREQUISITE: prerequisites repeat within a single rule";
 
+/// check_duplicate_prerequisite reports DUPLICATE_PREREQUISITE violations.
+fn check_duplicate_prerequisite(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs } => {
-                cs.len() > 1
-                    && cs.iter().all(|e2| e2.starts_with('-'))
-                    && !ts.iter().any(|e2| marked_ignored_targets.contains(e2))
+            ast::Ore::Ru { dc: _, os: _, ps, ts: _, cs: _ } => {
+                let mut seen_prerequisites: HashSet<&String> = HashSet::new();
+
+                ps.iter()
+                    .filter(|e2| e2.as_str() != ".WAIT")
+                    .any(|e2| !seen_prerequisites.insert(e2))
             }
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: SIMPLIFY_MINUS.to_string(),
+            offset: e.o,
+            message: DUPLICATE_PREREQUISITE.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_simplify_minus() {
+pub fn test_duplicate_prerequisite() {
     assert!(lint(
         &mock_md("-"),
-        ".POSIX:\nwelcome:\n\t-echo foo\n\t-echo bar\n\t-echo baz\n"
+        ".POSIX:\nfoo: a.c a.c b.c\n\tgcc -o foo a.c b.c\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&SIMPLIFY_MINUS.to_string()));
+    .contains(&DUPLICATE_PREREQUISITE.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\nwelcome:\n\t-echo foo\n\t-echo bar\n\techo baz\n"
+        ".POSIX:\nfoo: a.c b.c\n\tgcc -o foo a.c b.c\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&SIMPLIFY_MINUS.to_string()));
+    .contains(&DUPLICATE_PREREQUISITE.to_string()));
 
-    assert!(!lint(
-        &mock_md("-"),
-        ".POSIX:\n.IGNORE: welcome\nwelcome:\n\techo foo\n\techo bar\n\techo baz\n"
-    )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&SIMPLIFY_MINUS.to_string()));
+    assert!(
+        !lint(&mock_md("-"), ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 .WAIT test-2 .WAIT test-3\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\ntest-3:\n\techo \"Hey World!\"\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&DUPLICATE_PREREQUISITE.to_string())
+    );
 }
 
-pub static STRICT_POSIX: &str =
-    "STRICT_POSIX: lead makefiles with the \".POSIX:\" compliance marker, or else rename include files like *.include.mk";
-
-/// check_strict_posix reports STRICT_POSIX violations.
-fn check_strict_posix(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    if metadata.is_include_file {
-        return Vec::new();
-    }
-
-    let has_strict_posix: bool = gems.iter().any(|e| match &e.n {
-        ast::Ore::Ru { ps: _, ts, cs: _ } => ts.contains(&".POSIX".to_string()),
-        _ => false,
-    });
+pub static SELF_DEPENDENCY: &str =
+    "SELF_DEPENDENCY: target depends on itself, a likely circular mistake";
 
-    if !has_strict_posix {
-        return vec![Warning {
+/// check_self_dependency reports SELF_DEPENDENCY violations.
+fn check_self_dependency(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ps, ts, cs: _ } => ts.iter().any(|e2| ps.contains(e2)),
+            _ => false,
+        })
+        .map(|e| Warning {
             path: metadata.path.to_string(),
-            line: 1,
-            message: STRICT_POSIX.to_string(),
-        }];
-    }
-
-    Vec::new()
+            line: e.l,
+            offset: e.o,
+            message: SELF_DEPENDENCY.to_string(),
+        })
+        .collect()
 }
 
 #[test]
-pub fn test_strict_posix() {
-    let md_stdin: inspect::Metadata = mock_md("-");
-
-    assert!(lint(&md_stdin, "PKG = curl\n")
+pub fn test_self_dependency() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nfoo: foo\n\ttouch foo\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&STRICT_POSIX.to_string()));
+        .contains(&SELF_DEPENDENCY.to_string()));
 
-    assert!(!lint(&md_stdin, ".POSIX:\nPKG = curl\n")
+    assert!(
+        lint(&mock_md("-"), ".POSIX:\nfoo bar: a.c foo b.c\n\ttouch foo bar\n")
+            .unwrap()
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<String>>()
+            .contains(&SELF_DEPENDENCY.to_string())
+    );
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\tgcc -o foo foo.c\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&STRICT_POSIX.to_string()));
+        .contains(&SELF_DEPENDENCY.to_string()));
+}
 
-    let mut md_sys: inspect::Metadata = mock_md("sys.mk");
-    md_sys.is_include_file = true;
+pub static SELF_MODIFICATION: &str =
+    "SELF_MODIFICATION: commands rewriting the makefile itself harm reproducibility";
 
-    assert!(!lint(&md_sys, "PKG = curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&STRICT_POSIX.to_string()));
+/// modifies_path reports whether the given command
+/// appears to write to the named file.
+fn modifies_path(command: &str, filename: &str) -> bool {
+    let tokens: Vec<&str> = command
+        .trim_start_matches(['@', '-', '+'])
+        .split_whitespace()
+        .collect();
 
-    let mut md_include_mk: inspect::Metadata = mock_md("foo.include.mk");
-    md_include_mk.is_include_file = true;
+    for (i, token) in tokens.iter().enumerate() {
+        if let Some(stripped) = token.strip_prefix('>') {
+            let target: &str = stripped.trim_start_matches('>');
 
-    assert!(!lint(&md_include_mk, "PKG = curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&STRICT_POSIX.to_string()));
-}
+            if target == filename || (target.is_empty() && tokens.get(i + 1) == Some(&filename)) {
+                return true;
+            }
+        }
+    }
 
-pub static IMPLEMENTATTION_DEFINED_TARGET: &str = "IMPLEMENTATTION_DEFINED_TARGET: non-portable percent (%) or double-quote (\") in target or prerequisite";
+    match tokens.first().copied().unwrap_or("") {
+        "mv" => tokens.last() == Some(&filename),
+        "sed" => {
+            tokens.iter().any(|e| e.starts_with("-i")) && tokens.last() == Some(&filename)
+        }
+        _ => false,
+    }
+}
 
-/// check_implementation_defined_target reports IMPLEMENTATTION_DEFINED_TARGET violations.
-fn check_implementation_defined_target(
-    metadata: &inspect::Metadata,
-    gems: &[ast::Gem],
-) -> Vec<Warning> {
+/// check_self_modification reports SELF_MODIFICATION violations.
+fn check_self_modification(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps, ts, cs: _ } => {
-                ps.iter().any(|e2| e2.contains('%') || e2.contains('\"'))
-                    || ts.iter().any(|e2| e2.contains('%') || e2.contains('\"'))
-            }
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps: _,
+                ts: _,
+                cs,
+            } => cs
+                .iter()
+                .any(|e2| modifies_path(e2, &metadata.filename)),
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: IMPLEMENTATTION_DEFINED_TARGET.to_string(),
+            offset: e.o,
+            message: SELF_MODIFICATION.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_implementation_defined_target() {
+pub fn test_self_modification() {
     assert!(lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: all\nall: foo%\nfoo%: foo.c\n\tgcc -o foo% foo.c\n"
+        &mock_md("makefile"),
+        ".POSIX:\nall:\n\techo \"all:;\" > makefile\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&IMPLEMENTATTION_DEFINED_TARGET.to_string()));
+    .contains(&SELF_MODIFICATION.to_string()));
 
     assert!(lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: all\nall: \"foo\"\n\"foo\": foo.c\n\tgcc -o \"foo\" foo.c\n"
+        &mock_md("makefile"),
+        ".POSIX:\nall:\n\tsed -i -e \"s/a/b/\" makefile\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&IMPLEMENTATTION_DEFINED_TARGET.to_string()));
+    .contains(&SELF_MODIFICATION.to_string()));
+
+    assert!(lint(
+        &mock_md("makefile"),
+        ".POSIX:\nall:\n\tmv makefile.new makefile\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SELF_MODIFICATION.to_string()));
 
     assert!(!lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: all\nall: foo\nfoo: foo.c\n\tgcc -o foo foo.c\n"
+        &mock_md("makefile"),
+        ".POSIX:\nfoo.h:\n\techo \"#pragma once\" > foo.h\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&IMPLEMENTATTION_DEFINED_TARGET.to_string()));
+    .contains(&SELF_MODIFICATION.to_string()));
+
+    assert!(!lint(
+        &mock_md("makefile"),
+        ".POSIX:\nall:\n\tgrep -q foo makefile\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&SELF_MODIFICATION.to_string()));
 }
 
-pub static COMMAND_COMMENT: &str =
-    "COMMAND_COMMENT: comment embedded inside commands will forward to the shell interpreter";
+pub static WINDOWS_PATH_SEPARATOR: &str =
+    "WINDOWS_PATH_SEPARATOR: use forward slashes as path separators in targets and prerequisites";
 
-/// check_command_comment reports COMMAND_COMMENT violations.
-fn check_command_comment(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts: _, cs } => cs.iter().any(|e2| e2.contains('#')),
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: COMMAND_COMMENT.to_string(),
-        })
-        .collect()
+/// check_windows_path_separator reports WINDOWS_PATH_SEPARATOR violations.
+///
+/// This check scans raw text,
+/// as the grammar rejects backslashes in target and prerequisite literals,
+/// enriching the resulting parse errors with actionable guidance.
+pub fn check_windows_path_separator(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for (i, line) in makefile.lines().enumerate() {
+        if line.starts_with('\t') || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        let colon_index: usize = match line.find(':') {
+            None => continue,
+            Some(index) => index,
+        };
+
+        if let Some(equals_index) = line.find('=') {
+            if equals_index < colon_index {
+                continue;
+            }
+        }
+
+        if line[colon_index..].trim_start_matches(':').starts_with('=') {
+            continue;
+        }
+
+        let targets: &str = &line[..colon_index];
+        let prerequisites: &str = line[colon_index..]
+            .split(';')
+            .next()
+            .unwrap_or("")
+            .split('#')
+            .next()
+            .unwrap_or("")
+            .trim_end();
+        let prerequisites_sans_continuation: &str =
+            prerequisites.strip_suffix('\\').unwrap_or(prerequisites);
+
+        if targets.contains('\\') || prerequisites_sans_continuation.contains('\\') {
+            warnings.push(Warning {
+                path: metadata.path.to_string(),
+                line: 1 + i,
+                offset: 0,
+                message: WINDOWS_PATH_SEPARATOR.to_string(),
+            });
+        }
+    }
+
+    warnings
 }
 
 #[test]
-pub fn test_command_comment() {
-    assert!(lint(
+pub fn test_windows_path_separator() {
+    assert!(check_windows_path_separator(
         &mock_md("-"),
-        ".POSIX:\nfoo: foo.c\n\t#build foo\n\tgcc -o foo foo.c\n"
+        ".POSIX:\nfoo: dir\\foo.c\n\tgcc -o foo dir/foo.c\n"
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&COMMAND_COMMENT.to_string()));
-
-    assert!(
-        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t@#gcc -o foo foo.c\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&COMMAND_COMMENT.to_string())
-    );
-
-    assert!(
-        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t-#gcc -o foo foo.c\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&COMMAND_COMMENT.to_string())
-    );
+    .contains(&WINDOWS_PATH_SEPARATOR.to_string()));
 
-    assert!(
-        lint(&mock_md("-"), ".POSIX:\nfoo: foo.c\n\t+#gcc -o foo foo.c\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&COMMAND_COMMENT.to_string())
-    );
+    assert!(check_windows_path_separator(
+        &mock_md("-"),
+        ".POSIX:\ndir\\foo: foo.c\n\tgcc -o dir/foo foo.c\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&WINDOWS_PATH_SEPARATOR.to_string()));
 
-    assert!(lint(
+    assert!(!check_windows_path_separator(
         &mock_md("-"),
-        ".POSIX:\nfoo: foo.c\n\tgcc \\\n#output file \\\n\t\t-o foo \\\n\t\tfoo.c\n"
+        ".POSIX:\nfoo: dir/foo.c \\\n\tdir/bar.c\n\tgcc -o foo dir/foo.c dir/bar.c\n"
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&COMMAND_COMMENT.to_string()));
+    .contains(&WINDOWS_PATH_SEPARATOR.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        ".POSIX:\nfoo: foo.c\n#build foo\n\tgcc -o foo foo.c\n"
+        ".POSIX:\nfoo: dir/foo.c\n\tgcc -o foo dir/foo.c\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&COMMAND_COMMENT.to_string()));
+    .contains(&WINDOWS_PATH_SEPARATOR.to_string()));
 }
 
-pub static REPEATED_COMMAND_PREFIX: &str =
-    "REPEATED_COMMAND_PREFIX: redundant prefixes are superfluous";
-
-/// check_repeated_command_prefix reports REPEATED_COMMAND_PREFIX violations.
-fn check_repeated_command_prefix(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    gems.iter()
-        .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts: _, cs } => cs.iter().any(|e2| {
-                if BLANK_COMMAND_PATTERN.is_match(e2) {
-                    return false;
+pub static UNTERMINATED_MACRO_EXPANSION: &str =
+    "UNTERMINATED_MACRO_EXPANSION: close each \"$(\" or \"${\" macro expansion opener";
+
+/// unterminated_macro_opener searches a line
+/// for a macro expansion opener lacking a matching closing delimiter,
+/// returning the byte index of the first unmatched opener.
+fn unterminated_macro_opener(line: &str) -> Option<usize> {
+    let code: &str = line.split('#').next().unwrap_or("");
+    let mut openers: Vec<(usize, char)> = Vec::new();
+    let mut chars = code.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '$' => match chars.peek() {
+                Some((_, '$')) => {
+                    chars.next();
+                }
+                Some((_, '(')) => {
+                    chars.next();
+                    openers.push((i, ')'));
                 }
+                Some((_, '{')) => {
+                    chars.next();
+                    openers.push((i, '}'));
+                }
+                _ => {}
+            },
+            ')' | '}' if openers.last().map(|(_, closer)| *closer) == Some(c) => {
+                openers.pop();
+            }
+            _ => {}
+        }
+    }
 
-                let prefix: &str = COMMAND_PREFIX_PATTERN
-                    .captures(e2)
-                    .and_then(|e3| e3.name("prefix"))
-                    .map(|e3| e3.as_str())
-                    .unwrap_or("");
+    openers.first().map(|(i, _)| *i)
+}
 
-                prefix.matches('@').count() > 1
-                    || prefix.matches('+').count() > 1
-                    || prefix.matches('-').count() > 1
-            }),
-            _ => false,
-        })
-        .map(|e| Warning {
-            path: metadata.path.to_string(),
-            line: e.l,
-            message: REPEATED_COMMAND_PREFIX.to_string(),
-        })
-        .collect()
+/// check_unterminated_macro_expansion reports UNTERMINATED_MACRO_EXPANSION violations.
+///
+/// This check scans raw text,
+/// as the grammar accepts unbalanced expansion delimiters
+/// in macro values and commands.
+fn check_unterminated_macro_expansion(
+    metadata: &inspect::Metadata,
+    makefile: &str,
+) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut line_offset: usize = 0;
+
+    for (i, line) in makefile.split_inclusive('\n').enumerate() {
+        if let Some(opener_index) = unterminated_macro_opener(line) {
+            warnings.push(Warning {
+                path: metadata.path.to_string(),
+                line: 1 + i,
+                offset: line_offset + opener_index,
+                message: UNTERMINATED_MACRO_EXPANSION.to_string(),
+            });
+        }
+
+        line_offset += line.len();
+    }
+
+    warnings
 }
 
 #[test]
-pub fn test_repeated_command_prefix() {
-    assert!(lint(
+pub fn test_unterminated_macro_expansion() {
+    assert!(check_unterminated_macro_expansion(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: test\ntest:\n\t@@echo \"Hello World!\"\n"
+        ".POSIX:\nPKG = $(CURL\nall:;\n"
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+    .contains(&UNTERMINATED_MACRO_EXPANSION.to_string()));
 
-    assert!(lint(
+    assert!(check_unterminated_macro_expansion(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: test\ntest:\n\t--echo \"Hello World!\"\n"
+        ".POSIX:\nall:\n\techo ${PKG\n"
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+    .contains(&UNTERMINATED_MACRO_EXPANSION.to_string()));
 
-    assert!(lint(
+    assert_eq!(
+        check_unterminated_macro_expansion(&mock_md("-"), ".POSIX:\nPKG = $(CURL\nall:;\n"),
+        vec![Warning {
+            path: WARNING_DEFAULT_PATH.to_string(),
+            line: 2,
+            offset: 14,
+            message: UNTERMINATED_MACRO_EXPANSION.to_string(),
+        },]
+    );
+
+    assert!(!check_unterminated_macro_expansion(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: test\ntest:\n\t@-@echo \"Hello World!\"\n"
+        ".POSIX:\nPKG = $(CURL)\nall:\n\techo $${HOME} \"(\"\n"
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+    .contains(&UNTERMINATED_MACRO_EXPANSION.to_string()));
 
-    assert!(!lint(
+    assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = $(CURL) ${ZSTD}\nall:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNTERMINATED_MACRO_EXPANSION.to_string()));
+}
+
+pub static UNDOCUMENTED_TARGET: &str =
+    "UNDOCUMENTED_TARGET: precede each non-special rule with a documentation comment";
+
+/// check_undocumented_target reports UNDOCUMENTED_TARGET violations.
+///
+/// Unlike most checks, this opt-in scan operates on raw makefile text,
+/// re-parsing with comment retention.
+/// It is not registered in the default check set.
+pub fn check_undocumented_target(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let gems: Vec<ast::Gem> = match ast::parse_posix_with_comments(&metadata.path, makefile) {
+        Err(_) => return Vec::new(),
+        Ok(mk) => mk.ns,
+    };
+
+    let mut warnings: Vec<Warning> = Vec::new();
+    let mut previous_comment_line: usize = 0;
+
+    for gem in &gems {
+        match &gem.n {
+            ast::Ore::Cm { c: _ } => {
+                previous_comment_line = gem.l;
+            }
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ }
+                if ts.iter().any(|e| !ast::SPECIAL_TARGETS.contains(e))
+                    && gem.l != 1 + previous_comment_line =>
+            {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: gem.l,
+                    offset: gem.o,
+                    message: UNDOCUMENTED_TARGET.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    warnings
+}
+
+#[test]
+pub fn test_undocumented_target() {
+    assert!(check_undocumented_target(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: test\ntest:\n\t@+-echo \"Hello World!\"\n"
+        ".POSIX:\nall:\n\techo \"Hello World!\"\n"
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&REPEATED_COMMAND_PREFIX.to_string()));
+    .contains(&UNDOCUMENTED_TARGET.to_string()));
+
+    assert!(!check_undocumented_target(
+        &mock_md("-"),
+        ".POSIX:\n# greet the world\nall:\n\techo \"Hello World!\"\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNDOCUMENTED_TARGET.to_string()));
+
+    assert!(!check_undocumented_target(&mock_md("-"), ".POSIX:\n.SUFFIXES:\n")
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&UNDOCUMENTED_TARGET.to_string()));
+
+    assert!(check_undocumented_target(
+        &mock_md("-"),
+        ".POSIX:\n# greet the world\nall:\n\techo \"Hello World!\"\n\nlint:\n\tunmake .\n"
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&UNDOCUMENTED_TARGET.to_string()));
 }
 
-pub static BLANK_COMMAND: &str =
-    "BLANK_COMMAND: indeterminate behavior when empty commands are sent to assorted shell interpreters";
+pub static MACRO_NAMING: &str =
+    "MACRO_NAMING: name user-defined macros in UPPER_SNAKE_CASE convention";
 
-/// check_blank_command reports BLANK_COMMAND violations.
-fn check_blank_command(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+/// check_macro_naming reports MACRO_NAMING violations.
+///
+/// This opinionated, opt-in style check is not registered
+/// in the default check set. Enable it with [Linter::register],
+/// or with a [config::Config] enable list.
+pub fn check_macro_naming(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts: _, cs } => {
-                cs.iter().any(|e2| BLANK_COMMAND_PATTERN.is_match(e2))
+            ast::Ore::Mc { n, op: _, v: _ } => {
+                !n.starts_with('.')
+                    && !WELL_KNOWN_MACROS.contains(&n.as_str())
+                    && !READONLY_MACROS.contains(&n.as_str())
+                    && n.chars().any(|c| c.is_ascii_lowercase())
             }
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: BLANK_COMMAND.to_string(),
+            offset: e.o,
+            message: MACRO_NAMING.to_string(),
         })
         .collect()
 }
 
 #[test]
-pub fn test_blank_command() {
-    assert!(lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t@\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&BLANK_COMMAND.to_string()));
-
-    assert!(lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t-\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&BLANK_COMMAND.to_string()));
-
-    assert!(lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t+\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&BLANK_COMMAND.to_string()));
+pub fn test_macro_naming() {
+    assert!(check_macro_naming(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\npkg = curl\n").unwrap().ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MACRO_NAMING.to_string()));
 
-    assert!(
-        lint(&mock_md("-"), ".POSIX:\n.PHONY: test\ntest:\n\t@+- \n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&BLANK_COMMAND.to_string())
-    );
+    assert!(!check_macro_naming(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nPKG = curl\n").unwrap().ns
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&MACRO_NAMING.to_string()));
 
-    assert!(!lint(
+    assert!(!check_macro_naming(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: test\ntest:\n\techo \"Hello World!\"\n"
+        &ast::parse_posix("-", ".POSIX:\n.CURDIR = build2\n").unwrap().ns
     )
-    .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&BLANK_COMMAND.to_string()));
+    .contains(&MACRO_NAMING.to_string()));
 }
 
-pub static WHITESPACE_LEADING_COMMAND: &str =
-    "WHITESPACE_LEADING_COMMAND: questionable whitespace detected at the start of a command";
+/// DEFAULT_COMMAND_LENGTH_THRESHOLD caps command lengths in characters,
+/// as a conservative floor for common ARG_MAX environment limits.
+pub static DEFAULT_COMMAND_LENGTH_THRESHOLD: usize = 4096;
 
-/// check_whitespace_leading_command reports WHITESPACE_LEADING_COMMAND violations.
-fn check_whitespace_leading_command(
+pub static COMMAND_LENGTH: &str =
+    "COMMAND_LENGTH: very long commands risk exceeding ARG_MAX limits; split arguments or use response files";
+
+/// check_command_length_with reports COMMAND_LENGTH violations
+/// against a custom character length threshold.
+pub fn check_command_length_with(
     metadata: &inspect::Metadata,
     gems: &[ast::Gem],
+    threshold: usize,
 ) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts: _, cs } => cs
-                .iter()
-                .any(|e2| WHITESPACE_LEADING_COMMAND_PATTERN.is_match(e2)),
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ts: _,
+                ps: _,
+                cs,
+            } => cs.iter().any(|e2| e2.chars().count() > threshold),
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: WHITESPACE_LEADING_COMMAND.to_string(),
+            offset: e.o,
+            message: COMMAND_LENGTH.to_string(),
         })
         .collect()
 }
 
-#[test]
-pub fn test_whitespace_leading_command() {
-    assert!(lint(&mock_md("-"), "foo:\n\t gcc -o foo foo.c\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
-
-    assert!(lint(&mock_md("-"), "foo:\n\t\tgcc -o foo foo.c\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
-
-    assert!(lint(&mock_md("-"), "foo:\n\t@+- gcc -o foo foo.c\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
-
-    assert!(!lint(&mock_md("-"), "foo:\n\tgcc -o foo foo.c\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
-
-    assert!(!lint(&mock_md("-"), "foo:\n\t@+-gcc -o foo foo.c\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&WHITESPACE_LEADING_COMMAND.to_string()));
-
-    assert!(
-        !lint(&mock_md("-"), "foo:\n\tgcc \\\n\t\t-o \\\n\t\tfoo foo.c\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&WHITESPACE_LEADING_COMMAND.to_string())
-    );
-}
-
-pub static MISSING_FINAL_EOL: &str =
-    "MISSING_FINAL_EOL: UNIX text files may process poorly without a final LF";
-
-/// check_final_eol reports MISSING_FINAL_EOL violations.
-fn check_final_eol(metadata: &inspect::Metadata, _: &[ast::Gem]) -> Vec<Warning> {
-    if !metadata.is_empty && !metadata.has_final_eol {
-        return vec![Warning {
-            path: metadata.path.to_string(),
-            line: metadata.lines,
-            message: MISSING_FINAL_EOL.to_string(),
-        }];
-    }
-
-    Vec::new()
+/// check_command_length reports COMMAND_LENGTH violations
+/// against [DEFAULT_COMMAND_LENGTH_THRESHOLD].
+fn check_command_length(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_command_length_with(metadata, gems, DEFAULT_COMMAND_LENGTH_THRESHOLD)
 }
 
 #[test]
-pub fn test_final_eol() {
-    let mf_pkg: &str = ".POSIX:\nPKG = curl";
-    let mut md_pkg: inspect::Metadata = mock_md("-");
-    md_pkg.is_empty = &mf_pkg.len() == &0;
-    md_pkg.has_final_eol = &mf_pkg.chars().last().unwrap_or(' ') == &'\n';
-
-    assert!(lint(&md_pkg, &mf_pkg)
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&MISSING_FINAL_EOL.to_string()));
-
-    let mf_pkg_final_eol: &str = ".POSIX:\nPKG = curl\n";
-    let mut md_pkg_final_eol: inspect::Metadata = mock_md("-");
-    md_pkg_final_eol.is_empty = &mf_pkg_final_eol.len() == &0;
-    md_pkg_final_eol.has_final_eol = &mf_pkg_final_eol.chars().last().unwrap_or(' ') == &'\n';
+fn test_command_length() {
+    let long_makefile: String = format!(".POSIX:\nall:\n\techo {}\n", "a".repeat(4200));
 
-    assert!(!lint(&md_pkg_final_eol, &mf_pkg_final_eol)
+    assert!(lint(&mock_md("-"), &long_makefile)
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&MISSING_FINAL_EOL.to_string()));
-
-    let mf_empty: &str = "";
-    let mut md_empty: inspect::Metadata = mock_md("-");
-    md_empty.is_empty = &mf_empty.len() == &0;
-    md_empty.has_final_eol = &mf_empty.chars().last().unwrap_or(' ') == &'\n';
+        .contains(&COMMAND_LENGTH.to_string()));
 
-    assert!(!lint(&md_empty, &mf_empty)
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:\n\techo done\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&MISSING_FINAL_EOL.to_string()));
-}
-
-pub static PHONY_TARGET: &str = "PHONY_TARGET: mark common artifactless rules as .PHONY";
+        .contains(&COMMAND_LENGTH.to_string()));
 
-/// check_phony_target reports PHONY_TARGET violations.
-fn check_phony_target(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
-    let mut marked_phony_targets: HashSet<&String> = HashSet::new();
-    for gem in gems {
-        if let ast::Ore::Ru { ps, ts, cs: _ } = &gem.n {
-            if ts.contains(&".PHONY".to_string()) {
-                for p in ps {
-                    marked_phony_targets.insert(p);
-                }
-            }
-        }
-    }
+    assert!(!check_command_length_with(
+        &mock_md("-"),
+        &ast::parse_posix("-", ".POSIX:\nall:\n\techo done\n").unwrap().ns,
+        5,
+    )
+    .is_empty());
+}
+
+/// DEFAULT_CONTINUATION_THRESHOLD caps continuation newlines
+/// within one command; commands at or beyond the cap warn.
+pub static DEFAULT_CONTINUATION_THRESHOLD: usize = 10;
+
+pub static EXCESSIVE_CONTINUATION: &str =
+    "EXCESSIVE_CONTINUATION: commands spanning many continuation lines are hard to maintain; extract a script";
 
+/// check_excessive_continuation_with reports EXCESSIVE_CONTINUATION
+/// violations against a custom continuation count threshold.
+pub fn check_excessive_continuation_with(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+    threshold: usize,
+) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs: _ }
-                if !ts.iter().any(|e2| ast::SPECIAL_TARGETS.contains(e2))
-                    && ts.iter().any(|e2| !marked_phony_targets.contains(e2)) =>
-            {
-                ts.iter().any(|e2| {
-                    LOWER_CONVENTIONAL_PHONY_TARGETS_PATTERN.is_match(e2.to_lowercase().as_str())
-                })
-            }
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ts: _,
+                ps: _,
+                cs,
+            } => cs.iter().any(|e2| e2.matches("\\\n").count() >= threshold),
             _ => false,
         })
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
-            message: PHONY_TARGET.to_string(),
+            offset: e.o,
+            message: EXCESSIVE_CONTINUATION.to_string(),
         })
         .collect()
 }
 
+/// check_excessive_continuation reports EXCESSIVE_CONTINUATION violations
+/// against [DEFAULT_CONTINUATION_THRESHOLD].
+fn check_excessive_continuation(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_excessive_continuation_with(metadata, gems, DEFAULT_CONTINUATION_THRESHOLD)
+}
+
 #[test]
-pub fn test_phony_target() {
+fn test_excessive_continuation() {
+    let long_makefile: String = format!(
+        ".POSIX:\nall:\n\tgcc{}\n",
+        " \\\n\t\t-DX=1".repeat(12)
+    );
+
+    assert!(lint(&mock_md("-"), &long_makefile)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EXCESSIVE_CONTINUATION.to_string()));
+
     assert!(
-        lint(&mock_md("-"), ".POSIX:\nall:\n\techo \"Hello World!\"\n")
+        !lint(&mock_md("-"), ".POSIX:\nall:\n\tgcc \\\n\t\t-o foo foo.c\n")
             .unwrap()
             .into_iter()
             .map(|e| e.message)
             .collect::<Vec<String>>()
-            .contains(&PHONY_TARGET.to_string())
+            .contains(&EXCESSIVE_CONTINUATION.to_string())
     );
 
-    assert!(lint(
+    assert!(!check_excessive_continuation_with(
         &mock_md("-"),
-        ".POSIX:\nlint:;\ninstall:;\nuninstall:;\npublish:;\n"
+        &ast::parse_posix("-", ".POSIX:\nall:\n\tgcc \\\n\t\t-o foo foo.c\n")
+            .unwrap()
+            .ns,
+        1,
     )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&PHONY_TARGET.to_string()));
+    .is_empty());
+}
 
-    assert!(!lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: all\nall:\n\techo \"Hello World!\"\n"
-    )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&PHONY_TARGET.to_string()));
+pub static NON_ASCII_NAME: &str =
+    "NON_ASCII_NAME: non-ASCII characters in target, prerequisite, or macro names confuse some make implementations";
 
-    assert!(
-        lint(
-            &mock_md("-"),
-            ".POSIX:\ntest: test-1 test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n"
-        )
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&PHONY_TARGET.to_string()));
+/// check_non_ascii_name_with reports NON_ASCII_NAME violations,
+/// optionally extending the scan beyond names,
+/// to macro values and commands.
+pub fn check_non_ascii_name_with(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+    include_values: bool,
+) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Mc { n, op: _, v } => !n.is_ascii() || (include_values && !v.is_ascii()),
+            ast::Ore::Ru {
+                dc: _,
+                os: _,
+                ps,
+                ts,
+                cs,
+            } => {
+                ts.iter().chain(ps.iter()).any(|e2| !e2.is_ascii())
+                    || (include_values && cs.iter().any(|e2| !e2.is_ascii()))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: NON_ASCII_NAME.to_string(),
+        })
+        .collect()
+}
 
-    assert!(
-        !lint(
-            &mock_md("-"),
-            ".POSIX:\n.PHONY: test test-1 test-2\ntest: test-1 test-2\ntest-1:\n\techo \"Hello World!\"\ntest-2:\n\techo \"Hi World!\"\n"
-        )
+/// check_non_ascii_name reports NON_ASCII_NAME violations
+/// in names only, leaving comments, macro values,
+/// and commands free to contain UTF-8.
+fn check_non_ascii_name(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_non_ascii_name_with(metadata, gems, false)
+}
+
+#[test]
+fn test_non_ascii_name() {
+    assert!(lint(&mock_md("-"), ".POSIX:\nbuïld:;echo done\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&PHONY_TARGET.to_string()));
+        .contains(&NON_ASCII_NAME.to_string()));
 
-    assert!(
-        !lint(
-            &mock_md("-"),
-            ".POSIX:\n.PHONY: test\ntest: test-1 test-2\n.PHONY: test-1\ntest-1:\n\techo \"Hello World!\"\n.PHONY: test-2\ntest-2:\n\techo \"Hi World!\"\n"
-        )
+    assert!(!lint(&mock_md("-"), ".POSIX:\nMSG = café\nall:;echo $(MSG)\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&PHONY_TARGET.to_string()));
+        .contains(&NON_ASCII_NAME.to_string()));
 
-    assert!(lint(&mock_md("-"), ".POSIX:\nclean:\n\t-rm -rf bin\n")
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo done\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&PHONY_TARGET.to_string()));
+        .contains(&NON_ASCII_NAME.to_string()));
 
-    assert!(!lint(
+    assert!(!check_non_ascii_name_with(
         &mock_md("-"),
-        ".POSIX:\n.PHONY: clean\nclean:\n\t-rm -rf bin\n"
+        &ast::parse_posix("-", ".POSIX:\nMSG = café\nall:;echo $(MSG)\n")
+            .unwrap()
+            .ns,
+        true,
     )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&PHONY_TARGET.to_string()));
+    .is_empty());
+}
 
-    assert!(
-        !lint(&mock_md("-"), ".POSIX:\nport: cross-compile archive\n")
-            .unwrap()
-            .into_iter()
-            .map(|e| e.message)
-            .collect::<Vec<String>>()
-            .contains(&PHONY_TARGET.to_string())
-    );
+pub static EMPTY_MAKEFILE: &str = "EMPTY_MAKEFILE: empty makefile declares no instructions";
 
-    assert!(!lint(
-        &mock_md("-"),
-        ".POSIX:\n.PHONY: port\nport: cross-compile archive\n"
-    )
-    .unwrap()
-    .into_iter()
-    .map(|e| e.message)
-    .collect::<Vec<String>>()
-    .contains(&PHONY_TARGET.to_string()));
+/// check_empty_makefile reports EMPTY_MAKEFILE violations.
+fn check_empty_makefile(metadata: &inspect::Metadata, _: &[ast::Gem]) -> Vec<Warning> {
+    if metadata.is_empty && !metadata.is_include_file {
+        return vec![Warning {
+            path: metadata.path.to_string(),
+            line: 0,
+            offset: 0,
+            message: EMPTY_MAKEFILE.to_string(),
+        }];
+    }
 
-    assert!(!lint(&mock_md("-"), ".POSIX:\nempty:;\n")
+    Vec::new()
+}
+
+#[test]
+pub fn test_empty_makefile() {
+    let mut md_empty: inspect::Metadata = mock_md("-");
+    md_empty.is_empty = true;
+
+    assert!(lint(&md_empty, "")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&PHONY_TARGET.to_string()));
+        .contains(&EMPTY_MAKEFILE.to_string()));
+
+    let mut md_empty_include: inspect::Metadata = mock_md("foo.include.mk");
+    md_empty_include.is_empty = true;
+    md_empty_include.is_include_file = true;
+
+    assert!(!lint(&md_empty_include, "")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EMPTY_MAKEFILE.to_string()));
 
-    // Ensure that absent targets do not trigger a PHONY_TARGET warning,
-    // unlike some makefile linters in the past.
     assert!(!lint(&mock_md("-"), ".POSIX:\nPKG = curl\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&PHONY_TARGET.to_string()));
+        .contains(&EMPTY_MAKEFILE.to_string()));
 }
 
 pub static NO_RULES: &str =
@@ -1541,7 +8560,7 @@ fn check_no_rules(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warnin
     let has_nonspecial_rule: bool = !gems
         .iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ps: _, ts, cs: _ } => {
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ } => {
                 ts.iter().any(|e2| !ast::SPECIAL_TARGETS.contains(e2))
             }
             _ => false,
@@ -1553,6 +8572,7 @@ fn check_no_rules(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warnin
         return vec![Warning {
             path: metadata.path.to_string(),
             line: 0,
+            offset: 0,
             message: NO_RULES.to_string(),
         }];
     }
@@ -1589,11 +8609,20 @@ pub fn test_no_rules() {
         .contains(&NO_RULES.to_string()));
 }
 
+/// DEFAULT_RULE_NAME names the conventional first non-special,
+/// default rule.
+pub static DEFAULT_RULE_NAME: &str = "all";
+
 pub static RULE_ALL: &str =
     "RULE_ALL: makefiles conventionally name the first non-special, default rule \"all\", excepting certain *.include.mk files";
 
-/// check_rule_all reports RULE_ALL violations.
-fn check_rule_all(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+/// check_rule_all_with reports RULE_ALL violations,
+/// accepting the given name for the first non-special, default rule.
+pub fn check_rule_all_with(
+    metadata: &inspect::Metadata,
+    gems: &[ast::Gem],
+    default_rule_name: &str,
+) -> Vec<Warning> {
     if metadata.is_include_file {
         return Vec::new();
     }
@@ -1603,70 +8632,241 @@ fn check_rule_all(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warnin
 
     for gem in gems {
         match &gem.n {
-            ast::Ore::Ru { ps: _, ts, cs: _ }
+            ast::Ore::Ru { dc: _, os: _, ps: _, ts, cs: _ }
                 if !ts.is_empty() && ts.iter().all(|e2| !ast::SPECIAL_TARGETS.contains(e2)) =>
             {
                 found_nonspecial_target = true;
                 first_nonspecial_target = ts.first().unwrap();
                 break;
             }
-            _ => (),
-        }
-    }
+            _ => (),
+        }
+    }
+
+    if found_nonspecial_target && first_nonspecial_target != default_rule_name {
+        return vec![Warning {
+            path: metadata.path.to_string(),
+            line: 0,
+            offset: 0,
+            message: RULE_ALL.to_string(),
+        }];
+    }
+
+    Vec::new()
+}
+
+/// check_rule_all reports RULE_ALL violations,
+/// accepting the conventional [DEFAULT_RULE_NAME].
+fn check_rule_all(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    check_rule_all_with(metadata, gems, DEFAULT_RULE_NAME)
+}
+
+#[test]
+pub fn test_rule_all() {
+    assert!(lint(&mock_md("-"), "build:\n\techo \"Hello World!\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RULE_ALL.to_string()));
+
+    assert!(lint(&mock_md("-"), "help:\n\t@echo \"make all: build artifacts\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RULE_ALL.to_string()));
+
+    let md: inspect::Metadata = mock_md("-");
+
+    assert!(check_rule_all_with(
+        &md,
+        &ast::parse_posix(md.path.as_str(), "help:\n\t@echo \"make all: build artifacts\"\n")
+            .unwrap()
+            .ns,
+        "help"
+    )
+    .is_empty());
+
+    assert!(!check_rule_all_with(
+        &md,
+        &ast::parse_posix(md.path.as_str(), "build:\n\techo \"Hello World!\"\n")
+            .unwrap()
+            .ns,
+        "help"
+    )
+    .is_empty());
+
+    assert!(!lint(&mock_md("-"), "all:\n\techo \"Hello World!\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RULE_ALL.to_string()));
+
+    assert!(!lint(
+        &mock_md("-"),
+        "all: build\nbuild:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&RULE_ALL.to_string()));
+
+    assert!(!lint(&mock_md("-"), "PKG = curl\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RULE_ALL.to_string()));
+
+    let mut md_include = mock_md("foo.include.mk");
+    md_include.is_include_file = true;
+
+    assert!(!lint(&md_include, "build:\n\techo \"Hello World!\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RULE_ALL.to_string()));
+}
+
+pub static EXPORT_SPECIAL_TARGET: &str =
+    "EXPORT_SPECIAL_TARGET: special targets exporting macros to the environment are non-portable";
+
+/// check_export_special_target reports EXPORT_SPECIAL_TARGET violations.
+fn check_export_special_target(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    gems.iter()
+        .filter(|e| match &e.n {
+            ast::Ore::Ru { dc: _, os: _, ts, ps: _, cs: _ } => {
+                ts.iter().any(|e2| ast::EXPORT_SPECIAL_TARGETS.contains(e2))
+            }
+            _ => false,
+        })
+        .map(|e| Warning {
+            path: metadata.path.to_string(),
+            line: e.l,
+            offset: e.o,
+            message: EXPORT_SPECIAL_TARGET.to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_export_special_target() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n.EXPORT_ALL_VARIABLES:;\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EXPORT_SPECIAL_TARGET.to_string()));
+
+    assert!(lint(&mock_md("-"), ".POSIX:\n.EXPORT: PKG\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EXPORT_SPECIAL_TARGET.to_string()));
+
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:;echo done\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&EXPORT_SPECIAL_TARGET.to_string()));
+}
+
+pub static ONESHELL: &str =
+    "ONESHELL: .ONESHELL and shell state shared between commands are non-portable; chain dependent commands with &&";
+
+/// check_oneshell reports ONESHELL violations.
+fn check_oneshell(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for gem in gems {
+        if let ast::Ore::Ru { dc: _, os: _, ts, ps: _, cs } = &gem.n {
+            if ts.contains(&".ONESHELL".to_string()) {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: gem.l,
+                    offset: gem.o,
+                    message: ONESHELL.to_string(),
+                });
+
+                continue;
+            }
+
+            let mut assigned_variables: HashSet<&str> = HashSet::new();
+            let mut relies_on_state: bool = false;
+
+            for c in cs {
+                let command: &str = c.trim_start_matches(['@', '-', '+']).trim_start();
+
+                if assigned_variables.iter().any(|name| {
+                    command.contains(&format!("$${}", name))
+                        || command.contains(&format!("$${{{}}}", name))
+                }) {
+                    relies_on_state = true;
+                    break;
+                }
+
+                if let Some(captures) = SHELL_VARIABLE_ASSIGNMENT_PATTERN.captures(command) {
+                    if let Some(name) = captures.name("name") {
+                        assigned_variables.insert(name.as_str());
+                    }
+                }
+            }
 
-    if found_nonspecial_target && first_nonspecial_target != "all" {
-        return vec![Warning {
-            path: metadata.path.to_string(),
-            line: 0,
-            message: RULE_ALL.to_string(),
-        }];
+            if relies_on_state {
+                warnings.push(Warning {
+                    path: metadata.path.to_string(),
+                    line: gem.l,
+                    offset: gem.o,
+                    message: ONESHELL.to_string(),
+                });
+            }
+        }
     }
 
-    Vec::new()
+    warnings
 }
 
 #[test]
-pub fn test_rule_all() {
-    assert!(lint(&mock_md("-"), "build:\n\techo \"Hello World!\"\n")
+fn test_oneshell() {
+    assert!(lint(&mock_md("-"), ".POSIX:\n.ONESHELL:;\nall:;echo done\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&RULE_ALL.to_string()));
+        .contains(&ONESHELL.to_string()));
 
-    assert!(!lint(&mock_md("-"), "all:\n\techo \"Hello World!\"\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&RULE_ALL.to_string()));
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\tPKG=curl\n\techo \"$$PKG\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&ONESHELL.to_string()));
 
     assert!(!lint(
         &mock_md("-"),
-        "all: build\nbuild:\n\techo \"Hello World!\"\n"
+        ".POSIX:\nall:\n\tPKG=curl && echo \"$$PKG\"\n"
     )
     .unwrap()
     .into_iter()
     .map(|e| e.message)
     .collect::<Vec<String>>()
-    .contains(&RULE_ALL.to_string()));
-
-    assert!(!lint(&mock_md("-"), "PKG = curl\n")
-        .unwrap()
-        .into_iter()
-        .map(|e| e.message)
-        .collect::<Vec<String>>()
-        .contains(&RULE_ALL.to_string()));
+    .contains(&ONESHELL.to_string()));
 
-    let mut md_include = mock_md("foo.include.mk");
-    md_include.is_include_file = true;
-
-    assert!(!lint(&md_include, "build:\n\techo \"Hello World!\"\n")
+    assert!(!lint(&mock_md("-"), ".POSIX:\nall:\n\techo \"$$HOME\"\n")
         .unwrap()
         .into_iter()
         .map(|e| e.message)
         .collect::<Vec<String>>()
-        .contains(&RULE_ALL.to_string()));
+        .contains(&ONESHELL.to_string()));
 }
 
 pub static RESERVED_TARGET: &str =
@@ -1676,7 +8876,7 @@ pub static RESERVED_TARGET: &str =
 fn check_reserved_target(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec<Warning> {
     gems.iter()
         .filter(|e| match &e.n {
-            ast::Ore::Ru { ts, ps, cs: _ } => [&ts[..], &ps[..]].concat().iter().any(|e2| {
+            ast::Ore::Ru { dc: _, os: _, ts, ps, cs: _ } => [&ts[..], &ps[..]].concat().iter().any(|e2| {
                 RESERVED_TARGET_PATTERN.is_match(e2) && !ast::SPECIAL_TARGETS.contains(e2)
             }),
             _ => false,
@@ -1684,6 +8884,7 @@ fn check_reserved_target(metadata: &inspect::Metadata, gems: &[ast::Gem]) -> Vec
         .map(|e| Warning {
             path: metadata.path.to_string(),
             line: e.l,
+            offset: e.o,
             message: RESERVED_TARGET.to_string(),
         })
         .collect()
@@ -1722,7 +8923,189 @@ fn test_reserved_target() {
         .contains(&RESERVED_TARGET.to_string()));
 }
 
-/// lint generates warnings for a makefile.
+/// DynCheck implements a dynamically dispatched linter scan.
+pub type DynCheck = Box<dyn Fn(&inspect::Metadata, &[ast::Gem]) -> Vec<Warning>>;
+
+/// DynTextCheck implements a dynamically dispatched raw text linter scan.
+pub type DynTextCheck = Box<dyn Fn(&inspect::Metadata, &str) -> Vec<Warning>>;
+
+/// Linter runs a customizable series of checks,
+/// seeded with the built-in check sets.
+///
+/// Downstream integrations may [Linter::register] project specific checks
+/// without forking the built-ins.
+pub struct Linter {
+    /// checks collects the registered AST scans.
+    pub checks: Vec<DynCheck>,
+
+    /// text_checks collects the registered raw text scans.
+    pub text_checks: Vec<DynTextCheck>,
+}
+
+impl Linter {
+    /// new constructs a Linter with the built-in check sets.
+    pub fn new() -> Linter {
+        Linter {
+            checks: CHECKS
+                .iter()
+                .map(|e| {
+                    let check: Check = *e;
+                    Box::new(check) as DynCheck
+                })
+                .collect(),
+            text_checks: TEXT_CHECKS
+                .iter()
+                .map(|e| {
+                    let check: TextCheck = *e;
+                    Box::new(check) as DynTextCheck
+                })
+                .collect(),
+        }
+    }
+
+    /// register adds a custom AST scan.
+    pub fn register<F>(&mut self, check: F)
+    where
+        F: Fn(&inspect::Metadata, &[ast::Gem]) -> Vec<Warning> + 'static,
+    {
+        self.checks.push(Box::new(check));
+    }
+
+    /// register_text adds a custom raw text scan.
+    pub fn register_text<F>(&mut self, check: F)
+    where
+        F: Fn(&inspect::Metadata, &str) -> Vec<Warning> + 'static,
+    {
+        self.text_checks.push(Box::new(check));
+    }
+
+    /// lint generates warnings for a makefile.
+    pub fn lint(&self, metadata: &inspect::Metadata, makefile: &str) -> Result<Vec<Warning>, String> {
+        let gems: Vec<ast::Gem> = ast::parse_posix(&metadata.path, makefile)?.ns;
+        let mut warnings: Vec<Warning> = Vec::new();
+
+        for check in &self.checks {
+            warnings.extend(check(metadata, &gems));
+        }
+
+        for check in &self.text_checks {
+            warnings.extend(check(metadata, makefile));
+        }
+
+        escalate_posix_contradictions(&gems, &mut warnings);
+        Ok(finalize_warnings(makefile, warnings))
+    }
+}
+
+impl Default for Linter {
+    /// default generates a basic Linter.
+    fn default() -> Self {
+        Linter::new()
+    }
+}
+
+#[test]
+fn test_linter_register() {
+    let mut linter: Linter = Linter::new();
+    linter.register(|metadata, gems| {
+        gems.iter()
+            .filter(|e| match &e.n {
+                ast::Ore::Mc { n, op: _, v: _ } => n == "FORBIDDEN",
+                _ => false,
+            })
+            .map(|e| Warning {
+                path: metadata.path.to_string(),
+                line: e.l,
+                offset: e.o,
+                message: "CUSTOM: do not use the FORBIDDEN macro".to_string(),
+            })
+            .collect()
+    });
+
+    assert!(linter
+        .lint(&mock_md("-"), ".POSIX:\nFORBIDDEN = 1\nall:\n\techo \"Hello World!\"\n")
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&"CUSTOM: do not use the FORBIDDEN macro".to_string()));
+
+    assert_eq!(
+        lint(&mock_md("-"), ".POSIX:\nPKG = curl\nall:\n\techo \"Hello World!\"\n").unwrap(),
+        Linter::new()
+            .lint(&mock_md("-"), ".POSIX:\nPKG = curl\nall:\n\techo \"Hello World!\"\n")
+            .unwrap()
+    );
+
+    assert_eq!(
+        lint(&mock_md("-"), ".POSIX:\nMAKEFLAGS = -j\nall:\n\techo done\n").unwrap(),
+        Linter::new()
+            .lint(&mock_md("-"), ".POSIX:\nMAKEFLAGS = -j\nall:\n\techo done\n")
+            .unwrap()
+    );
+}
+
+/// dedupe_warnings drops repeated warnings sharing a path, line, and message,
+/// preserving the first occurrence in order.
+///
+/// Checks scanning multiple fields of a single rule,
+/// such as several offending commands,
+/// may otherwise repeat the same report for one line.
+fn dedupe_warnings(warnings: Vec<Warning>) -> Vec<Warning> {
+    let mut seen: HashSet<(String, usize, String)> = HashSet::new();
+
+    warnings
+        .into_iter()
+        .filter(|e| seen.insert((e.path.to_string(), e.line, e.message.to_string())))
+        .collect()
+}
+
+/// suppressions scans raw makefile text for suppression directive comments
+/// of the form "# unmake-disable <RULE_ID> [<RULE_ID> ...]",
+/// mapping each directive line number to its suppressed rule ids.
+///
+/// A directive suppresses matching warnings on the immediately following line only.
+fn suppressions(makefile: &str) -> HashMap<usize, Vec<String>> {
+    makefile
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            line.trim()
+                .strip_prefix('#')
+                .map(|e| e.trim())
+                .and_then(|e| e.strip_prefix("unmake-disable"))
+                .map(|e| {
+                    (
+                        1 + i,
+                        e.split_whitespace().map(|e2| e2.to_string()).collect(),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// finalize_warnings applies the post-check pipeline shared by
+/// the lint entrypoints: dropping warnings matching
+/// "# unmake-disable <RULE_ID>" directive comments
+/// on the immediately preceding line,
+/// then deduplicating repeated reports.
+fn finalize_warnings(makefile: &str, mut warnings: Vec<Warning>) -> Vec<Warning> {
+    let suppressed: HashMap<usize, Vec<String>> = suppressions(makefile);
+    warnings.retain(|e| {
+        !suppressed
+            .get(&e.line.saturating_sub(1))
+            .map(|ids| ids.iter().any(|id| id == rule_id(&e.message)))
+            .unwrap_or(false)
+    });
+
+    dedupe_warnings(warnings)
+}
+
+/// lint generates warnings for a makefile,
+/// applying the built-in check sets.
+///
+/// Warnings matching an "# unmake-disable <RULE_ID>" directive comment
+/// on the immediately preceding line are suppressed.
 pub fn lint(metadata: &inspect::Metadata, makefile: &str) -> Result<Vec<Warning>, String> {
     let gems: Vec<ast::Gem> = ast::parse_posix(&metadata.path, makefile)?.ns;
     let mut warnings: Vec<Warning> = Vec::new();
@@ -1731,7 +9114,327 @@ pub fn lint(metadata: &inspect::Metadata, makefile: &str) -> Result<Vec<Warning>
         warnings.extend(check(metadata, &gems));
     }
 
-    Ok(warnings)
+    warnings.extend(lint_text(metadata, makefile));
+    escalate_posix_contradictions(&gems, &mut warnings);
+    Ok(finalize_warnings(makefile, warnings))
+}
+
+/// is_opt_in_rule reports whether the given rule id names an
+/// opt-in check available for [config::Config] enable lists.
+pub fn is_opt_in_rule(id: &str) -> bool {
+    OPT_IN_CHECKS.iter().any(|(e, _)| *e == id)
+        || OPT_IN_TEXT_CHECKS.iter().any(|(e, _)| *e == id)
+}
+
+/// apply_config extends lint results with any opt-in checks
+/// activated by the enable list in the given rule configuration,
+/// and reapplies RULE_ALL for any configured default_rule_name.
+///
+/// Opt-in AST scans are skipped for unparseable makefiles;
+/// opt-in raw text scans run regardless.
+///
+/// Suppression directives and deduplication
+/// cover the combined results like [lint].
+pub fn apply_config(
+    metadata: &inspect::Metadata,
+    makefile: &str,
+    config: &config::Config,
+    mut warnings: Vec<Warning>,
+) -> Vec<Warning> {
+    if config.enable.is_empty() && config.default_rule_name.is_none() {
+        return warnings;
+    }
+
+    if let Ok(ast) = ast::parse_posix(&metadata.path, makefile) {
+        for (id, check) in OPT_IN_CHECKS.iter() {
+            if config.enables(id) {
+                warnings.extend(check(metadata, &ast.ns));
+            }
+        }
+
+        if let Some(default_rule_name) = &config.default_rule_name {
+            warnings.retain(|e| rule_id(&e.message) != "RULE_ALL");
+            warnings.extend(check_rule_all_with(metadata, &ast.ns, default_rule_name));
+        }
+    }
+
+    for (id, check) in OPT_IN_TEXT_CHECKS.iter() {
+        if config.enables(id) {
+            warnings.extend(check(metadata, makefile));
+        }
+    }
+
+    finalize_warnings(makefile, warnings)
+}
+
+#[test]
+pub fn test_apply_config() {
+    let makefile: &str = ".POSIX:\n.PHONY: all\nall:\n\techo done # TODO: tidy\n";
+    let ws: Vec<Warning> = lint(&mock_md("-"), makefile).unwrap();
+
+    assert_eq!(
+        apply_config(&mock_md("-"), makefile, &config::Config::default(), ws),
+        lint(&mock_md("-"), makefile).unwrap()
+    );
+
+    let config: config::Config =
+        serde_json::from_str(r#"{ "enable": ["TODO_COMMENT"] }"#).unwrap();
+
+    assert!(apply_config(
+        &mock_md("-"),
+        makefile,
+        &config,
+        lint(&mock_md("-"), makefile).unwrap()
+    )
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&TODO_COMMENT.to_string()));
+
+    assert!(is_opt_in_rule("TODO_COMMENT"));
+    assert!(is_opt_in_rule("PHONY_SPLIT"));
+    assert!(!is_opt_in_rule("PHONY_TARGET"));
+
+    let help_config: config::Config =
+        serde_json::from_str(r#"{ "default_rule_name": "help" }"#).unwrap();
+    let help_makefile: &str = ".POSIX:\n.PHONY: help\nhelp:\n\t@echo \"make all: build\"\n";
+
+    assert!(lint(&mock_md("-"), help_makefile)
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&RULE_ALL.to_string()));
+
+    assert!(!apply_config(
+        &mock_md("-"),
+        help_makefile,
+        &help_config,
+        lint(&mock_md("-"), help_makefile).unwrap()
+    )
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&RULE_ALL.to_string()));
+}
+
+/// lint_timed generates warnings for a makefile like [lint],
+/// while accumulating wall clock durations per check function name,
+/// for profiling linter performance across many files.
+pub fn lint_timed(
+    metadata: &inspect::Metadata,
+    makefile: &str,
+    timings: &mut HashMap<&'static str, time::Duration>,
+) -> Result<Vec<Warning>, String> {
+    let gems: Vec<ast::Gem> = ast::parse_posix(&metadata.path, makefile)?.ns;
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for (name, check) in NAMED_CHECKS.iter() {
+        let start: time::Instant = time::Instant::now();
+        warnings.extend(check(metadata, &gems));
+        *timings.entry(name).or_default() += start.elapsed();
+    }
+
+    for (name, check) in NAMED_TEXT_CHECKS.iter() {
+        let start: time::Instant = time::Instant::now();
+        warnings.extend(check(metadata, makefile));
+        *timings.entry(name).or_default() += start.elapsed();
+    }
+
+    escalate_posix_contradictions(&gems, &mut warnings);
+    Ok(finalize_warnings(makefile, warnings))
+}
+
+#[test]
+pub fn test_lint_timed() {
+    let mut timings: HashMap<&'static str, time::Duration> = HashMap::new();
+    let ws: Vec<Warning> = lint_timed(
+        &mock_md("-"),
+        ".POSIX:\nall:\n\techo done\n",
+        &mut timings,
+    )
+    .unwrap();
+
+    assert_eq!(ws, lint(&mock_md("-"), ".POSIX:\nall:\n\techo done\n").unwrap());
+    assert_eq!(timings.len(), NAMED_CHECKS.len() + NAMED_TEXT_CHECKS.len());
+    assert!(timings.contains_key("check_strict_posix"));
+}
+
+#[test]
+pub fn test_dedupe_warnings() {
+    assert_eq!(
+        lint(
+            &mock_md("-"),
+            ".POSIX:\nfoo: foo.c\n\t#configure\n\t#build\n\tgcc -o foo foo.c\n"
+        )
+        .unwrap()
+        .into_iter()
+        .map(|e| e.message)
+        .filter(|e| e == COMMAND_COMMENT)
+        .count(),
+        1
+    );
+}
+
+#[test]
+pub fn test_suppressions() {
+    assert!(!lint(
+        &mock_md("-"),
+        ".POSIX:\n# unmake-disable PHONY_TARGET\nall:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_TARGET.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n# unmake-disable SELF_DEPENDENCY\nall:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_TARGET.to_string()));
+
+    assert!(lint(
+        &mock_md("-"),
+        ".POSIX:\n# unmake-disable PHONY_TARGET\n\nall:\n\techo \"Hello World!\"\n"
+    )
+    .unwrap()
+    .into_iter()
+    .map(|e| e.message)
+    .collect::<Vec<String>>()
+    .contains(&PHONY_TARGET.to_string()));
+}
+
+lazy_static::lazy_static! {
+    /// PORTABILITY_RULE_IDS collects the rule identifiers considered by
+    /// [is_posix_clean]: the UB_* undefined behavior family, the
+    /// STRICT_POSIX marker check, and checks for GNU/BSD syntax extensions.
+    ///
+    /// Style-class checks, such as PHONY_TARGET and SIMPLIFY_AT,
+    /// are deliberately excluded.
+    pub static ref PORTABILITY_RULE_IDS: Vec<&'static str> = vec![
+        "DOUBLE_COLON_RULE",
+        "GNU_VPATH",
+        "IMPLEMENTATTION_DEFINED_TARGET",
+        "MAKECMDGOALS_EXPANSION",
+        "NONPORTABLE_ECHO_FLAG",
+        "NONPORTABLE_FUNCTION",
+        "ONESHELL",
+        "ORDER_ONLY_PREREQUISITE",
+        "PATTERN_RULE",
+        "SHELL_ASSIGNMENT",
+        "SHELL_METACHARACTER_TARGET",
+        "STRICT_POSIX",
+        "UB_AMBIGUOUS_INCLUDE",
+        "UB_LATE_POSIX_MARKER",
+        "UB_MAKEFLAGS_MACRO",
+        "UB_SHELL_MACRO",
+        "WINDOWS_PATH_SEPARATOR",
+    ];
+}
+
+/// is_posix_clean reports whether a makefile string passes
+/// every portability-class check listed in [PORTABILITY_RULE_IDS].
+///
+/// Unparseable strings report false.
+/// Style-class warnings do not affect the result.
+pub fn is_posix_clean(pth: &str, makefile: &str) -> bool {
+    let metadata: inspect::Metadata = inspect::analyze_virtual(pth, makefile);
+
+    match lint(&metadata, makefile) {
+        Err(_) => false,
+        Ok(ws) => !ws
+            .iter()
+            .any(|e| PORTABILITY_RULE_IDS.contains(&rule_id(&e.message))),
+    }
+}
+
+#[test]
+pub fn test_is_posix_clean() {
+    assert!(is_posix_clean(
+        "-",
+        ".POSIX:\n.PHONY: all\nall:\n\techo \"Hello World!\"\n"
+    ));
+
+    assert!(!is_posix_clean("-", ".POSIX:\nSRC = $(wildcard *.c)\n"));
+
+    assert!(!is_posix_clean(
+        "-",
+        ".POSIX:\nMAKEFLAGS = -j\nall:\n\techo done\n"
+    ));
+
+    assert!(!is_posix_clean("-", "all:\n\techo \"Hello World!\"\n"));
+
+    assert!(!is_posix_clean("-", "fo:::o\n"));
+
+    assert!(is_posix_clean(
+        "-",
+        ".POSIX:\nall:\n\techo \"Hello World!\"\n"
+    ));
+}
+
+/// lint_recover generates warnings for a makefile,
+/// skipping past syntax errors in order to report
+/// multiple parse problems in a single pass.
+///
+/// Returns warnings for the recoverable portion of the AST,
+/// alongside any parse error messages.
+///
+/// "# unmake-disable <RULE_ID>" directive comments
+/// suppress warnings like [lint].
+pub fn lint_recover(metadata: &inspect::Metadata, makefile: &str) -> (Vec<Warning>, Vec<String>) {
+    let (ast, errors) = ast::parse_posix_recover(&metadata.path, makefile);
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for check in CHECKS.iter() {
+        warnings.extend(check(metadata, &ast.ns));
+    }
+
+    warnings.extend(lint_text(metadata, makefile));
+    escalate_posix_contradictions(&ast.ns, &mut warnings);
+    (finalize_warnings(makefile, warnings), errors)
+}
+
+#[test]
+pub fn test_lint_recover() {
+    let (ws, errors) = lint_recover(&mock_md("-"), "fo:::o\nall: ${MAKECMDGOALS}\n\techo done\n");
+
+    assert_eq!(errors.len(), 1);
+    assert!(ws
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKECMDGOALS_EXPANSION.to_string()));
+
+    let (ws_suppressed, errors_suppressed) = lint_recover(
+        &mock_md("-"),
+        "fo:::o\n# unmake-disable MAKECMDGOALS_EXPANSION\nall: ${MAKECMDGOALS}\n\techo done\n",
+    );
+
+    assert_eq!(errors_suppressed.len(), 1);
+    assert!(!ws_suppressed
+        .into_iter()
+        .map(|e| e.message)
+        .collect::<Vec<String>>()
+        .contains(&MAKECMDGOALS_EXPANSION.to_string()));
+}
+
+/// lint_text generates warnings for raw makefile text,
+/// without requiring a successful parse.
+///
+/// Useful for enriching parse errors with actionable guidance.
+pub fn lint_text(metadata: &inspect::Metadata, makefile: &str) -> Vec<Warning> {
+    let mut warnings: Vec<Warning> = Vec::new();
+
+    for check in TEXT_CHECKS.iter() {
+        warnings.extend(check(metadata, makefile));
+    }
+
+    warnings
 }
 
 #[test]
@@ -1748,6 +9451,7 @@ pub fn test_line_numbers() {
         vec![Warning {
             path: WARNING_DEFAULT_PATH.to_string(),
             line: 2,
+            offset: 9,
             message: UB_LATE_POSIX_MARKER.to_string(),
         },]
     );